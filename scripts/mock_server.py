@@ -0,0 +1,276 @@
+#!/usr/bin/env python3
+"""Backend falso do Quickshare para desenvolvimento local.
+
+Guarda tudo em memória e implementa a API usada pelo frontend, incluindo o
+protocolo de upload em partes. Só depende da biblioteca padrão do Python.
+
+    python3 scripts/mock_server.py --port 7777 --chunk-fail-rate 0.2
+"""
+
+import argparse
+import hashlib
+import json
+import random
+import re
+import uuid
+from datetime import datetime, timedelta, timezone
+from email.parser import BytesParser
+from email.policy import default as default_policy
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+FILES = {}    # file_id -> {"filename", "data", "expires_at", "max_downloads", "downloads", "sha256"}
+UPLOADS = {}  # upload_id -> {"filename", "size", "chunk_size", "chunks": {index: bytes}}
+ARGS = None
+
+
+def expires_at(expires_in):
+    expiry = datetime.now(timezone.utc) + timedelta(seconds=expires_in)
+    return expiry.isoformat().replace("+00:00", "Z")
+
+
+def store_file(filename, data, expires_in=86400, max_downloads=None):
+    file_id = uuid.uuid4().hex
+    sha256 = hashlib.sha256(data).hexdigest()
+    FILES[file_id] = {
+        "filename": filename,
+        "data": data,
+        "expires_at": expires_at(expires_in),
+        "max_downloads": max_downloads,
+        "downloads": 0,
+        "deletion_token": uuid.uuid4().hex,
+        "sha256": sha256,
+    }
+    return {
+        "file_id": file_id,
+        "download_url": f"/download/{file_id}",
+        "expires_at": FILES[file_id]["expires_at"],
+        "expires_in": expires_in,
+        "max_downloads": max_downloads,
+        "deletion_token": FILES[file_id]["deletion_token"],
+        "sha256": sha256,
+    }
+
+
+# O hash é opcional; quando vem, tem que bater com o que chegou
+def sha256_matches(expected, data):
+    return expected is None or expected.lower() == hashlib.sha256(data).hexdigest()
+
+
+class Handler(BaseHTTPRequestHandler):
+    protocol_version = "HTTP/1.1"
+
+    def end_headers(self):
+        self.send_header("Access-Control-Allow-Origin", "*")
+        self.send_header("Access-Control-Allow-Methods", "GET, HEAD, POST, PUT, PATCH, DELETE, OPTIONS")
+        self.send_header("Access-Control-Allow-Headers", "*")
+        self.send_header("Access-Control-Expose-Headers", "*")
+        super().end_headers()
+
+    def send_json(self, status, payload):
+        body = json.dumps(payload).encode()
+        self.send_response(status)
+        self.send_header("Content-Type", "application/json")
+        self.send_header("Content-Length", str(len(body)))
+        self.end_headers()
+        self.wfile.write(body)
+
+    def send_empty(self, status):
+        self.send_response(status)
+        self.send_header("Content-Length", "0")
+        self.end_headers()
+
+    def read_body(self):
+        length = int(self.headers.get("Content-Length", 0))
+        return self.rfile.read(length)
+
+    def do_OPTIONS(self):
+        self.send_empty(204)
+
+    def do_GET(self):
+        if self.path == "/capabilities":
+            return self.send_json(200, {
+                "chunked_upload": not ARGS.no_chunks,
+                "chunk_size": ARGS.chunk_size,
+            })
+
+        if m := re.fullmatch(r"/download/(\w+)", self.path):
+            entry = FILES.get(m[1])
+            if entry is None:
+                return self.send_empty(404)
+            data, etag = entry["data"], f'"{m[1]}"'
+            # Range só vale se o If-Range (quando enviado) bate com o ETag atual
+            start, end = 0, len(data) - 1
+            requested = re.fullmatch(r"bytes=(\d+)-(\d*)", self.headers.get("Range", ""))
+            partial = bool(requested) and self.headers.get("If-Range", etag) == etag
+            if partial:
+                start = int(requested[1])
+                end = min(int(requested[2] or end), end)
+                if start >= len(data):
+                    self.send_response(416)
+                    self.send_header("Content-Range", f"bytes */{len(data)}")
+                    self.send_header("Content-Length", "0")
+                    return self.end_headers()
+            self.send_response(206 if partial else 200)
+            self.send_header("Content-Type", "application/octet-stream")
+            self.send_header("Content-Length", str(end + 1 - start))
+            self.send_header("Content-Disposition", f'attachment; filename="{entry["filename"]}"')
+            self.send_header("Accept-Ranges", "bytes")
+            self.send_header("ETag", etag)
+            if partial:
+                self.send_header("Content-Range", f"bytes {start}-{end}/{len(data)}")
+            self.end_headers()
+            self.wfile.write(data[start:end + 1])
+            # Só conta o download que chega ao fim do arquivo; limite atingido, o arquivo some
+            if end == len(data) - 1:
+                entry["downloads"] += 1
+                if entry["max_downloads"] and entry["downloads"] >= entry["max_downloads"]:
+                    del FILES[m[1]]
+            return
+
+        if m := re.fullmatch(r"/files/(\w+)", self.path):
+            entry = FILES.get(m[1])
+            if entry is None:
+                return self.send_empty(404)
+            return self.send_json(200, {
+                "file_id": m[1],
+                "filename": entry["filename"],
+                "size": len(entry["data"]),
+                "expires_at": entry["expires_at"],
+                "max_downloads": entry["max_downloads"],
+                "sha256": entry["sha256"],
+            })
+
+        if m := re.fullmatch(r"/uploads/(\w+)", self.path):
+            upload = UPLOADS.get(m[1])
+            if upload is None:
+                return self.send_empty(404)
+            return self.send_json(200, {"received_chunks": sorted(upload["chunks"])})
+
+        self.send_empty(404)
+
+    # Verificação de existência sem baixar o conteúdo
+    def do_HEAD(self):
+        if m := re.fullmatch(r"/download/(\w+)", self.path):
+            entry = FILES.get(m[1])
+            if entry is None:
+                return self.send_empty(404)
+            self.send_response(200)
+            self.send_header("Content-Type", "application/octet-stream")
+            self.send_header("Content-Length", str(len(entry["data"])))
+            self.send_header("Accept-Ranges", "bytes")
+            self.send_header("ETag", f'"{m[1]}"')
+            return self.end_headers()
+
+        self.send_empty(404)
+
+    def do_POST(self):
+        if self.path == "/upload":
+            content_type = self.headers.get("Content-Type", "")
+            raw = b"Content-Type: " + content_type.encode() + b"\r\n\r\n" + self.read_body()
+            message = BytesParser(policy=default_policy).parsebytes(raw)
+            fields, upload = {}, None
+            for part in message.iter_parts():
+                name = part.get_param("name", header="content-disposition")
+                if name == "file":
+                    upload = (part.get_filename() or "arquivo", part.get_payload(decode=True) or b"")
+                else:
+                    fields[name] = (part.get_payload(decode=True) or b"").decode()
+            if upload is None:
+                return self.send_json(400, {"error": "campo 'file' ausente"})
+            if not sha256_matches(fields.get("sha256"), upload[1]):
+                return self.send_json(422, {"error": "sha256 não confere"})
+            return self.send_json(200, store_file(
+                *upload,
+                expires_in=int(fields.get("expires_in", 86400)),
+                max_downloads=int(fields["max_downloads"]) if "max_downloads" in fields else None,
+            ))
+
+        if self.path == "/uploads":
+            init = json.loads(self.read_body())
+            upload_id = uuid.uuid4().hex
+            UPLOADS[upload_id] = {**init, "chunks": {}}
+            return self.send_json(201, {"upload_id": upload_id})
+
+        if m := re.fullmatch(r"/uploads/(\w+)/complete", self.path):
+            self.read_body()
+            upload = UPLOADS.get(m[1])
+            if upload is None:
+                return self.send_empty(404)
+            data = b"".join(upload["chunks"][i] for i in sorted(upload["chunks"]))
+            if len(data) != upload["size"]:
+                return self.send_json(409, {"error": "partes faltando"})
+            if not sha256_matches(upload.get("sha256"), data):
+                del UPLOADS[m[1]]
+                return self.send_json(422, {"error": "sha256 não confere"})
+            del UPLOADS[m[1]]
+            return self.send_json(200, store_file(
+                upload["filename"],
+                data,
+                expires_in=upload.get("expires_in", 86400),
+                max_downloads=upload.get("max_downloads"),
+            ))
+
+        self.send_empty(404)
+
+    def do_PUT(self):
+        if m := re.fullmatch(r"/uploads/(\w+)/chunks/(\d+)", self.path):
+            data = self.read_body()
+            upload = UPLOADS.get(m[1])
+            if upload is None:
+                return self.send_empty(404)
+            # Simula conexões instáveis para exercitar a retentativa
+            if random.random() < ARGS.chunk_fail_rate:
+                return self.send_empty(503)
+            upload["chunks"][int(m[2])] = data
+            return self.send_empty(204)
+
+        self.send_empty(404)
+
+    # Ações do dono: estender a validade ou trocar o limite de downloads
+    def do_PATCH(self):
+        if m := re.fullmatch(r"/files/(\w+)", self.path):
+            changes = json.loads(self.read_body() or b"{}")
+            entry = FILES.get(m[1])
+            if entry is None:
+                return self.send_empty(404)
+            if self.headers.get("X-Deletion-Token") != entry["deletion_token"]:
+                return self.send_empty(403)
+            if "expires_in" in changes:
+                entry["expires_at"] = expires_at(int(changes["expires_in"]))
+            if "max_downloads" in changes:
+                entry["max_downloads"] = changes["max_downloads"]
+            return self.send_json(200, {
+                "expires_at": entry["expires_at"],
+                "max_downloads": entry["max_downloads"],
+            })
+
+        self.send_empty(404)
+
+    def do_DELETE(self):
+        if m := re.fullmatch(r"/files/(\w+)", self.path):
+            entry = FILES.get(m[1])
+            if entry is None:
+                return self.send_empty(404)
+            if self.headers.get("X-Deletion-Token") != entry["deletion_token"]:
+                return self.send_empty(403)
+            del FILES[m[1]]
+            return self.send_empty(204)
+
+        self.send_empty(404)
+
+
+def main():
+    global ARGS
+    parser = argparse.ArgumentParser(description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter)
+    parser.add_argument("--port", type=int, default=7777)
+    parser.add_argument("--chunk-size", type=int, default=1024 * 1024)
+    parser.add_argument("--chunk-fail-rate", type=float, default=0.0)
+    parser.add_argument("--no-chunks", action="store_true", help="não anuncia upload em partes")
+    ARGS = parser.parse_args()
+
+    print(f"Mock do Quickshare em http://localhost:{ARGS.port}")
+    ThreadingHTTPServer(("0.0.0.0", ARGS.port), Handler).serve_forever()
+
+
+if __name__ == "__main__":
+    main()
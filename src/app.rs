@@ -1,18 +1,100 @@
 use yew::prelude::*;
+use yew_router::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use crate::components::{error_banner::ErrorBanner, file_list::FileList, upload_form::UploadForm};
-use crate::services::api::ApiClient;
+use crate::components::{
+    error_banner::ErrorBanner, nav_bar::NavBar, offline_banner::OfflineBanner, toasts::Toasts,
+};
+use crate::hooks::use_api::use_api;
+use crate::hooks::use_online_status::use_online_status;
+use crate::hooks::use_pending_deletions::use_pending_deletions;
+use crate::hooks::use_upload_queue::use_upload_queue;
+use crate::models::config::AppConfig;
+use crate::models::file::FileStatus;
+use crate::router::{switch, Route};
+use crate::services::config::ConfigService;
+use crate::services::outbox::OutboxService;
+use crate::services::partial_download::PartialDownloadService;
+use crate::services::pwa::PwaService;
+use crate::services::storage::StorageService;
 use crate::store::files_store::{FilesStore, FilesStoreAction, FilesStoreContext};
-use crate::utils::constants::API_URL;
 use gloo::console;
 
 #[function_component(App)]
 pub fn app() -> Html {
-    let store = use_reducer(|| FilesStore::load());
+    let config = use_state(|| None::<AppConfig>);
 
-    console::log!(format!("{}", &API_URL));
+    // Carrega /config.json antes de falar com o backend
+    {
+        let config = config.clone();
+        use_effect_with((), move |_| {
+            spawn_local(PwaService::register_service_worker());
+            spawn_local(async move {
+                config.set(Some(ConfigService::load().await));
+            });
+            || ()
+        });
+    }
+
+    match (*config).clone() {
+        Some(config) => html! {
+            <ContextProvider<AppConfig> context={config}>
+                <AppShell />
+            </ContextProvider<AppConfig>>
+        },
+        None => html! {
+            <div class="container">
+                <div class="validating">
+                    <div class="spinner"></div>
+                    <p>{"Carregando configuração..."}</p>
+                </div>
+            </div>
+        },
+    }
+}
+
+#[function_component(AppShell)]
+fn app_shell() -> Html {
+    let store = use_reducer(FilesStore::load);
+    let api = use_api();
 
-    // Efeito para validar arquivos ao montar o componente
+    // Envia os arquivos da fila de upload
+    use_upload_queue(store.clone(), api.clone());
+    use_online_status(store.clone());
+    use_pending_deletions(store.clone(), api.clone());
+
+    // Restaura os envios que ficaram na caixa de saída (IndexedDB) e descarta
+    // downloads parciais de arquivos que já expiraram e sessões de upload velhas
+    {
+        let store = store.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let records = OutboxService::load().await;
+                if !records.is_empty() {
+                    store.dispatch(FilesStoreAction::RestoreUploads(records));
+                }
+                PartialDownloadService::prune_expired().await;
+                StorageService::prune_upload_sessions();
+            });
+            || ()
+        });
+    }
+
+    // Consulta os recursos do backend (upload em partes etc.)
+    {
+        let store = store.clone();
+        let api = api.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let capabilities = api.fetch_capabilities().await;
+                store.dispatch(FilesStoreAction::SetCapabilities(capabilities));
+            });
+            || ()
+        });
+    }
+
+    // Efeito para validar arquivos ao montar o componente; a lista continua
+    // visível e cada arquivo expirado vai para o arquivo de expirados assim
+    // que sua verificação termina
     {
         let store = store.clone();
         use_effect_with((), move |_| {
@@ -22,10 +104,13 @@ pub fn app() -> Html {
                 
                 if !current_files.is_empty() {
                     console::log!("Validando arquivos existentes...");
-                    let valid_files = ApiClient::validate_files(current_files).await;
-                    
-                    console::log!(format!("Arquivos válidos: {}", valid_files.len()));
-                    store.dispatch(FilesStoreAction::SetValidatedFiles(valid_files));
+                    let on_result = {
+                        let store = store.clone();
+                        Callback::from(move |(file_id, status): (String, FileStatus)| {
+                            store.dispatch(FilesStoreAction::FileValidated(file_id, status));
+                        })
+                    };
+                    api.validate_files(current_files, on_result).await;
                 }
                 
                 store.dispatch(FilesStoreAction::SetValidating(false));
@@ -36,24 +121,19 @@ pub fn app() -> Html {
 
     html! {
         <ContextProvider<FilesStoreContext> context={store.clone()}>
-            <div class="container">
-                <header>
-                    <h1>{"📁 Quickshare"}</h1>
-                    <p class="subtitle">{"Compartilhe arquivos temporários (válidos por 24 horas)"}</p>
-                </header>
-                <ErrorBanner />
-                if store.validating {
-                    <div class="upload-loading">
-                        <div class="spinner"></div>
-                        <span>{"Verificando arquivos..."}</span>
-                    </div>
-                } else {
-                    <>
-                        <UploadForm />
-                        <FileList />
-                    </>
-                }
-            </div>
+            <BrowserRouter>
+                <div class="container">
+                    <header>
+                        <h1>{"📁 Quickshare"}</h1>
+                        <p class="subtitle">{"Compartilhe arquivos temporários (de 1 hora a 7 dias, com limite de downloads opcional)"}</p>
+                    </header>
+                    <NavBar />
+                    <OfflineBanner />
+                    <ErrorBanner />
+                    <Switch<Route> render={switch} />
+                </div>
+                <Toasts />
+            </BrowserRouter>
         </ContextProvider<FilesStoreContext>>
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,72 @@
+use yew::prelude::*;
+use crate::models::operation::Operation;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::formatters::{format_bytes, format_duration, format_throughput};
+
+#[derive(Properties, PartialEq)]
+pub struct DownloadProgressProps {
+    pub file_id: String,
+}
+
+// Barra de progresso de um download em andamento, com botão de cancelar
+#[function_component(DownloadProgress)]
+pub fn download_progress(props: &DownloadProgressProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    let on_cancel = {
+        let store = store.clone();
+        let file_id = props.file_id.clone();
+        Callback::from(move |_: MouseEvent| {
+            store.dispatch(FilesStoreAction::CancelOperation(Operation::Download(file_id.clone())));
+        })
+    };
+
+    let progress = store.download_progress.get(&props.file_id);
+    // Sem Content-Length não dá para calcular a porcentagem
+    let known_total = progress.filter(|p| p.total > 0);
+
+    let bar = match known_total {
+        Some(p) => html! {
+            <div class="progress-bar">
+                <div class="progress-fill" style={format!("width: {:.1}%", p.percent())}></div>
+            </div>
+        },
+        None => html! {
+            <div class="progress-bar indeterminate">
+                <div class="progress-fill"></div>
+            </div>
+        },
+    };
+
+    let details = match (progress, known_total) {
+        (_, Some(p)) => html! {
+            <>
+                <span>{format!("{:.0}%", p.percent())}</span>
+                <span>{format!("{} de {}", format_bytes(p.loaded), format_bytes(p.total))}</span>
+                <span>{format_throughput(p.throughput())}</span>
+                if let Some(secs) = p.eta_secs() {
+                    <span>{format!("faltam {}", format_duration(secs))}</span>
+                }
+            </>
+        },
+        (Some(p), None) => html! {
+            <>
+                <span>{format!("{} recebidos", format_bytes(p.loaded))}</span>
+                <span>{format_throughput(p.throughput())}</span>
+            </>
+        },
+        (None, None) => html! { <span>{"Iniciando download..."}</span> },
+    };
+
+    html! {
+        <div class="download-progress">
+            <div class="download-progress-bar">
+                { bar }
+                <button onclick={on_cancel} class="btn-cancel" type="button" title="Cancelar download">
+                    {"✕"}
+                </button>
+            </div>
+            <div class="progress-details">{ details }</div>
+        </div>
+    }
+}
@@ -0,0 +1,116 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use gloo::events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, DataTransfer, DragEvent, File};
+use yew::prelude::*;
+use crate::hooks::use_file_upload::use_file_upload;
+
+// Área de soltura que cobre a página inteira: aceita arquivos arrastados
+// da área de trabalho e arquivos/imagens colados com Ctrl+V.
+#[function_component(DropZone)]
+pub fn drop_zone() -> Html {
+    let hovering = use_state(|| false);
+    let upload = use_file_upload();
+
+    {
+        let hovering = hovering.setter();
+        let upload = upload.clone();
+        use_effect_with((), move |_| {
+            let window = web_sys::window().expect("window not found");
+            let options = EventListenerOptions::enable_prevent_default();
+            // dragenter/dragleave disparam também nos elementos filhos
+            let depth = Rc::new(Cell::new(0));
+
+            let on_enter = {
+                let depth = depth.clone();
+                let hovering = hovering.clone();
+                EventListener::new_with_options(&window, "dragenter", options, move |e| {
+                    if carries_files(e) {
+                        e.prevent_default();
+                        depth.set(depth.get() + 1);
+                        hovering.set(true);
+                    }
+                })
+            };
+
+            let on_over = EventListener::new_with_options(&window, "dragover", options, move |e| {
+                if carries_files(e) {
+                    e.prevent_default();
+                }
+            });
+
+            let on_leave = {
+                let depth = depth.clone();
+                let hovering = hovering.clone();
+                EventListener::new(&window, "dragleave", move |e| {
+                    if carries_files(e) {
+                        depth.set((depth.get() - 1).max(0));
+                        if depth.get() == 0 {
+                            hovering.set(false);
+                        }
+                    }
+                })
+            };
+
+            let on_drop = {
+                let upload = upload.clone();
+                EventListener::new_with_options(&window, "drop", options, move |e| {
+                    let Some(data) = e.dyn_ref::<DragEvent>().and_then(|e| e.data_transfer()) else {
+                        return;
+                    };
+                    depth.set(0);
+                    hovering.set(false);
+                    // Texto solto num campo (ex.: a senha) segue o comportamento normal
+                    let files = files_from(&data);
+                    if files.is_empty() {
+                        return;
+                    }
+                    e.prevent_default();
+                    upload.emit(files);
+                })
+            };
+
+            let on_paste = EventListener::new(&window, "paste", move |e| {
+                let Some(data) = e.dyn_ref::<ClipboardEvent>().and_then(|e| e.clipboard_data()) else {
+                    return;
+                };
+                // Colagem de texto segue o comportamento normal do navegador
+                let files = files_from(&data);
+                if !files.is_empty() {
+                    upload.emit(files);
+                }
+            });
+
+            move || drop((on_enter, on_over, on_leave, on_drop, on_paste))
+        });
+    }
+
+    if !*hovering {
+        return html! {};
+    }
+
+    html! {
+        <div class="drop-overlay">
+            <div class="drop-overlay-content">
+                <span class="drop-overlay-icon">{"📥"}</span>
+                <span>{"Solte os arquivos para enviar"}</span>
+            </div>
+        </div>
+    }
+}
+
+// Durante o arraste `files()` ainda vem vazio (o navegador só libera no drop),
+// então dragenter/dragover olham os tipos; arrastar só texto não conta
+fn carries_files(e: &web_sys::Event) -> bool {
+    e.dyn_ref::<DragEvent>()
+        .and_then(|e| e.data_transfer())
+        .map(|data| data.types().iter().any(|t| t.as_string().as_deref() == Some("Files")))
+        .unwrap_or(false)
+}
+
+fn files_from(data: &DataTransfer) -> Vec<File> {
+    data.files()
+        .map(|list| (0..list.length()).filter_map(|i| list.get(i)).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,37 @@
+use yew::prelude::*;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::formatters::format_datetime;
+
+// Links que o servidor confirmou como expirados ou removidos
+#[function_component(ExpiredList)]
+pub fn expired_list() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    if store.expired_files.is_empty() {
+        return html! {};
+    }
+
+    let on_clear = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(FilesStoreAction::ClearExpired))
+    };
+
+    html! {
+        <div class="expired-list">
+            <div class="expired-header">
+                <h2>{"Expirados"}</h2>
+                <button onclick={on_clear} class="btn-clear-expired" type="button">
+                    {"Limpar expirados"}
+                </button>
+            </div>
+            {
+                for store.expired_files.iter().map(|file| html! {
+                    <div class="expired-item" key={file.file_id.clone()}>
+                        <span class="file-name">{"📄 "}{&file.filename}</span>
+                        <span class="expired-date">{"Expirou em "}{format_datetime(&file.expires_at)}</span>
+                    </div>
+                })
+            }
+        </div>
+    }
+}
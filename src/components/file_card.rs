@@ -1,10 +1,21 @@
 use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::components::download_progress::DownloadProgress;
+use crate::components::file_preview::FilePreview;
+use crate::components::integrity_badge::IntegrityBadge;
+use crate::components::owner_actions::OwnerActions;
+use crate::components::password_prompt::PasswordPrompt;
+use crate::components::qr_modal::QrModal;
+use crate::hooks::use_api::use_api;
 use crate::hooks::use_clipboard::use_clipboard;
+use crate::hooks::use_share::use_share;
+use crate::models::error::ApiError;
 use crate::models::file::StoredFile;
-use crate::services::api::ApiClient;
-use crate::store::files_store::FilesStoreContext;
-use crate::utils::constants::API_URL;
-use crate::utils::formatters::format_expires;
+use crate::router::Route;
+use crate::services::share::ShareService;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::formatters::{format_download_limit, format_expires, format_expiry};
+use crate::utils::links::share_url;
 
 #[derive(Properties, PartialEq)]
 pub struct FileCardProps {
@@ -14,56 +25,205 @@ pub struct FileCardProps {
 #[function_component(FileCard)]
 pub fn file_card(props: &FileCardProps) -> Html {
     let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let api = use_api();
     let copy_to_clipboard = use_clipboard();
-    let download_url = format!("{}/download/{}", API_URL, props.file.file_id);
+    // Aponta para a página do destinatário; a chave vai no #fragmento
+    let link = share_url(&props.file);
     let expires = format_expires(&props.file.expires_at);
 
     let on_copy = {
-        let url = download_url.clone();
+        let url = link.clone();
         let copy = copy_to_clipboard.clone();
         Callback::from(move |_| {
             copy.emit(url.clone());
         })
     };
 
+    // Remoções podem ser desfeitas pelo aviso que aparece em seguida
+    let on_remove = {
+        let store = store.clone();
+        let file_id = props.file.file_id.clone();
+        Callback::from(move |_| {
+            store.dispatch(FilesStoreAction::RemoveFile(file_id.clone(), false));
+        })
+    };
+
+    let on_delete = {
+        let store = store.clone();
+        let file_id = props.file.file_id.clone();
+        Callback::from(move |_| {
+            store.dispatch(FilesStoreAction::RemoveFile(file_id.clone(), true));
+        })
+    };
+
+    let memory_only_token = store.settings.token_lock.is_some()
+        && props.file.deletion_token.is_some()
+        && props.file.sealed_token.is_none();
+
+    let managing = use_state(|| false);
+    let on_toggle_manage = {
+        let managing = managing.clone();
+        Callback::from(move |_| managing.set(!*managing))
+    };
+
+    let showing_qr = use_state(|| false);
+    let on_show_qr = {
+        let showing_qr = showing_qr.clone();
+        Callback::from(move |_| showing_qr.set(true))
+    };
+    let on_close_qr = {
+        let showing_qr = showing_qr.clone();
+        Callback::from(move |_| showing_qr.set(false))
+    };
+
+    // Menu nativo do sistema, quando o navegador oferece
+    let can_share = ShareService::can_share();
+    let share = use_share();
+    let on_share = {
+        let url = link.clone();
+        let title = props.file.filename.clone();
+        Callback::from(move |_| {
+            share.emit((title.clone(), url.clone()));
+        })
+    };
+
     let downloading = store.downloading_files.contains(&props.file.file_id);
+    let download_error = store.download_errors.get(&props.file.file_id);
+    let wrong_password = download_error == Some(&ApiError::WrongPassword);
+    // O servidor já não tem o arquivo: o item da lista não serve mais
+    let missing = matches!(download_error, Some(ApiError::NotFound | ApiError::Gone));
+    let prompting = use_state(|| false);
 
     let on_download = {
+        let api = api.clone();
         let store = store.clone();
         let file = props.file.clone();
+        let prompting = prompting.clone();
         Callback::from(move |_: web_sys::MouseEvent| {
-            ApiClient::download_file(file.clone(), store.clone());
+            if file.password_protected {
+                prompting.set(true);
+            } else {
+                api.download_file(file.clone(), None, store.clone());
+            }
+        })
+    };
+
+    let on_password = {
+        let api = api.clone();
+        let store = store.clone();
+        let file = props.file.clone();
+        let prompting = prompting.clone();
+        Callback::from(move |password: String| {
+            prompting.set(false);
+            api.download_file(file.clone(), Some(password), store.clone());
+        })
+    };
+
+    let on_password_cancel = {
+        let store = store.clone();
+        let file_id = props.file.file_id.clone();
+        let prompting = prompting.clone();
+        Callback::from(move |_| {
+            prompting.set(false);
+            store.dispatch(FilesStoreAction::SetDownloadError(file_id.clone(), None));
         })
     };
 
     html! {
         <div class="file-card">
             <div class="file-info">
-                <div class="file-name-large">
+                <Link<Route>
+                    to={Route::FileDetails { file_id: props.file.file_id.clone() }}
+                    classes="file-name-large"
+                >
                     {"📄 "}{&props.file.filename}
-                </div>
+                </Link<Route>>
                 <div class="file-meta">
                     <span>{"🆔 ID: "}{&props.file.file_id}</span>
                     <span>{"⏰ Expira em: "}{expires}</span>
+                    if let Some(expires_in) = props.file.expires_in {
+                        <span>{"⌛ Validade: "}{format_expiry(expires_in)}</span>
+                    }
+                    if props.file.max_downloads.is_some() {
+                        <span class="badge-limit">
+                            { if props.file.max_downloads == Some(1) { "🔥 " } else { "⬇️ " } }
+                            {format_download_limit(props.file.max_downloads)}
+                        </span>
+                    }
+                    if props.file.encryption_key.is_some() {
+                        <span class="badge-encrypted">{"🔒 Criptografado"}</span>
+                    }
+                    if props.file.password_protected {
+                        <span class="badge-encrypted">{"🔑 Protegido por senha"}</span>
+                    }
+                    <IntegrityBadge file_id={props.file.file_id.clone()} />
+                    if props.file.stale {
+                        <span
+                            class="badge-stale"
+                            title="Não foi possível falar com o servidor na última verificação"
+                        >
+                            {"⚠️ Não verificado"}
+                        </span>
+                    }
                 </div>
+                if downloading {
+                    <DownloadProgress file_id={props.file.file_id.clone()} />
+                } else if *prompting || wrong_password {
+                    <PasswordPrompt
+                        on_submit={on_password}
+                        on_cancel={on_password_cancel}
+                        {wrong_password}
+                    />
+                } else if let Some(error) = download_error {
+                    <div class="download-error">
+                        {"❌ "}{error.to_string()}
+                        if missing {
+                            <button onclick={on_remove.clone()} class="btn-remove" type="button">
+                                {"🗑️ Remover da lista"}
+                            </button>
+                        }
+                    </div>
+                }
+                if props.file.deletion_token.is_none() && props.file.sealed_token.is_some() {
+                    <div class="owner-locked-hint">
+                        {"🔒 Tokens de dono bloqueados. "}
+                        <Link<Route> to={Route::Settings}>{"Desbloquear nas configurações"}</Link<Route>>
+                        {" para excluir, estender ou limitar este arquivo."}
+                    </div>
+                }
+                // Recebido com o bloqueio fechado: sem a chave não dá para cifrar,
+                // então o token não foi para o disco
+                if memory_only_token {
+                    <div class="owner-locked-hint">
+                        {"⚠️ O token de dono deste arquivo está só na memória e se perde ao fechar a página. "}
+                        <Link<Route> to={Route::Settings}>{"Desbloquear nas configurações"}</Link<Route>>
+                        {" para guardá-lo cifrado."}
+                    </div>
+                }
+                if *managing && props.file.deletion_token.is_some() {
+                    <OwnerActions file={props.file.clone()} />
+                }
+                <FilePreview file={props.file.clone()} />
             </div>
 
             <div class="file-actions">
-                if downloading {
-                    <div class="upload-loading">
-                        <div class="spinner"></div>
-                        <span>{"..."}</span>
-                    </div>
-                } else {
+                <button
+                    onclick={on_download}
+                    class="btn-download"
+                    disabled={downloading}
+                >
+                    { if downloading { "⬇️ Baixando…" } else { "⬇️ Baixar" } }
+                </button>
+
+                if can_share {
                     <button
-                        onclick={on_download}
-                        class="btn-download"
-                        disabled={downloading}
+                        onclick={on_share}
+                        class="btn-copy"
+                        type="button"
                     >
-                        { "⬇️ Baixar" }
+                        {"📤 Compartilhar…"}
                     </button>
                 }
-
                 <button
                     onclick={on_copy}
                     class="btn-copy"
@@ -71,7 +231,45 @@ pub fn file_card(props: &FileCardProps) -> Html {
                 >
                     {"📋 Copiar link"}
                 </button>
+                <button
+                    onclick={on_show_qr}
+                    class="btn-copy"
+                    type="button"
+                    title="Mostrar QR code do link"
+                >
+                    {"📱 QR code"}
+                </button>
+                <button
+                    onclick={on_remove}
+                    class="btn-remove"
+                    type="button"
+                    title="Tira o arquivo só deste navegador; o link continua valendo"
+                >
+                    {"🗑️ Remover da lista"}
+                </button>
+                if props.file.deletion_token.is_some() {
+                    <button
+                        onclick={on_toggle_manage}
+                        class="btn-copy"
+                        type="button"
+                        title="Estender a validade ou mudar o limite de downloads"
+                    >
+                        {"⚙️ Gerenciar"}
+                    </button>
+                    <button
+                        onclick={on_delete}
+                        class="btn-remove btn-danger"
+                        type="button"
+                        title="Apaga o arquivo no servidor; o link para de funcionar"
+                    >
+                        {"❌ Excluir do servidor"}
+                    </button>
+                }
             </div>
+
+            if *showing_qr {
+                <QrModal url={link.clone()} filename={props.file.filename.clone()} on_close={on_close_qr} />
+            }
         </div>
     }
 }
\ No newline at end of file
@@ -2,22 +2,39 @@ use yew::prelude::*;
 use crate::components::file_card::FileCard;
 use crate::store::files_store::FilesStoreContext;
 
+#[derive(Properties, PartialEq)]
+pub struct FileListProps {
+    #[prop_or(AttrValue::Static("Arquivos Enviados"))]
+    pub title: AttrValue,
+    // Mostra só os N mais recentes
+    #[prop_or_default]
+    pub limit: Option<usize>,
+}
+
 #[function_component(FileList)]
-pub fn file_list() -> Html {
+pub fn file_list(props: &FileListProps) -> Html {
     let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
 
     if store.files.is_empty() {
         return html! {};
     }
 
+    let limit = props.limit.unwrap_or(store.files.len());
+
     html! {
         <div class="files-list">
-            <h2>{"Arquivos Enviados"}</h2>
+            <h2>{&props.title}</h2>
+            if store.validating {
+                <div class="validating-hint">
+                    <div class="spinner spinner-small"></div>
+                    <span>{"Verificando se os links ainda existem..."}</span>
+                </div>
+            }
             {
-                for store.files.iter().map(|file| {
-                    html! { <FileCard file={file.clone()} /> }
+                for store.files.iter().take(limit).map(|file| {
+                    html! { <FileCard key={file.file_id.clone()} file={file.clone()} /> }
                 })
             }
         </div>
     }
-}
\ No newline at end of file
+}
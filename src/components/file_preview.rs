@@ -0,0 +1,148 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::AbortController;
+use yew::prelude::*;
+use crate::hooks::use_api::use_api;
+use crate::models::error::ApiError;
+use crate::models::file::StoredFile;
+use crate::services::preview::{Preview, PreviewContent, PreviewService};
+use crate::utils::mime::PreviewKind;
+
+#[derive(Properties, PartialEq)]
+pub struct FilePreviewProps {
+    pub file: StoredFile,
+}
+
+#[derive(Clone, PartialEq)]
+enum PreviewState {
+    Closed,
+    Loading,
+    Ready(Preview),
+    Failed(ApiError),
+}
+
+// Painel de pré-visualização; só busca o arquivo quando o usuário pede
+#[function_component(FilePreview)]
+pub fn file_preview(props: &FilePreviewProps) -> Html {
+    let api = use_api();
+    let state = use_state(|| PreviewState::Closed);
+    let controller = use_mut_ref(|| None::<AbortController>);
+
+    // Cancela a busca e libera o blob: ao fechar, trocar de prévia ou desmontar
+    {
+        let controller = controller.clone();
+        use_effect_with((*state).clone(), move |state| {
+            let state = state.clone();
+            move || {
+                if let PreviewState::Ready(preview) = &state {
+                    PreviewService::release(preview);
+                }
+                if matches!(state, PreviewState::Loading)
+                    && let Some(controller) = controller.borrow_mut().take()
+                {
+                    controller.abort();
+                }
+            }
+        });
+    }
+
+    // Arquivos com senha precisariam pedir a senha só para a prévia. Com
+    // limite de downloads, a prévia gastaria um download (ou apagaria o
+    // arquivo, no caso de "apagar após o 1º download")
+    if props.file.password_protected || props.file.max_downloads.is_some() {
+        return html! {};
+    }
+
+    let on_open = {
+        let api = api.clone();
+        let file = props.file.clone();
+        let state = state.clone();
+        let controller = controller.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Ok(abort) = AbortController::new() else {
+                return;
+            };
+            let signal = abort.signal();
+            *controller.borrow_mut() = Some(abort);
+            state.set(PreviewState::Loading);
+
+            let (api, file, state) = (api.clone(), file.clone(), state.clone());
+            spawn_local(async move {
+                let result = PreviewService::load(&api, &file, &signal).await;
+                // Fechado enquanto carregava: descarta o que chegou
+                if signal.aborted() {
+                    if let Ok(preview) = &result {
+                        PreviewService::release(preview);
+                    }
+                    return;
+                }
+                match result {
+                    Err(ApiError::Aborted) => {}
+                    Ok(preview) => state.set(PreviewState::Ready(preview)),
+                    Err(e) => state.set(PreviewState::Failed(e)),
+                }
+            });
+        })
+    };
+
+    let on_close = {
+        let state = state.clone();
+        Callback::from(move |_: MouseEvent| state.set(PreviewState::Closed))
+    };
+
+    let body = match &*state {
+        PreviewState::Closed => {
+            return html! {
+                <button onclick={on_open} class="btn-copy btn-preview" type="button">
+                    {"👁️ Pré-visualizar"}
+                </button>
+            };
+        }
+        PreviewState::Loading => html! {
+            <div class="upload-loading">
+                <div class="spinner"></div>
+                <span>{"Carregando pré-visualização..."}</span>
+            </div>
+        },
+        PreviewState::Failed(e) => html! {
+            <div class="download-error">{"❌ "}{e.to_string()}</div>
+        },
+        PreviewState::Ready(preview) => render_preview(preview, &props.file.filename),
+    };
+
+    html! {
+        <div class="file-preview">
+            <div class="file-preview-header">
+                <span>{"👁️ Pré-visualização"}</span>
+                <button onclick={on_close} class="btn-cancel" type="button" title="Fechar pré-visualização">
+                    {"✕"}
+                </button>
+            </div>
+            { body }
+        </div>
+    }
+}
+
+fn render_preview(preview: &Preview, filename: &str) -> Html {
+    match (&preview.kind, &preview.content) {
+        (_, PreviewContent::Text { text, truncated }) => html! {
+            <>
+                <pre class="preview-text">{text}</pre>
+                if *truncated {
+                    <p class="file-preview-hint">{"Mostrando só o começo do arquivo."}</p>
+                }
+            </>
+        },
+        (PreviewKind::Image, PreviewContent::Url(url)) => html! {
+            <img class="preview-media" src={url.clone()} alt={filename.to_string()} />
+        },
+        (PreviewKind::Audio, PreviewContent::Url(url)) => html! {
+            <audio class="preview-media" src={url.clone()} controls=true />
+        },
+        (PreviewKind::Video, PreviewContent::Url(url)) => html! {
+            <video class="preview-media" src={url.clone()} controls=true />
+        },
+        (_, PreviewContent::Url(url)) => html! {
+            <iframe class="preview-pdf" src={url.clone()} title={filename.to_string()} />
+        },
+    }
+}
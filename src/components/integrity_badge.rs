@@ -0,0 +1,28 @@
+use yew::prelude::*;
+use crate::models::file::Integrity;
+use crate::store::files_store::FilesStoreContext;
+
+#[derive(Properties, PartialEq)]
+pub struct IntegrityBadgeProps {
+    pub file_id: String,
+}
+
+// Resultado da conferência do SHA-256 no último download deste arquivo
+#[function_component(IntegrityBadge)]
+pub fn integrity_badge(props: &IntegrityBadgeProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    match store.download_integrity.get(&props.file_id) {
+        Some(Integrity::Verified) => html! {
+            <span class="badge-verified" title="O SHA-256 do download bate com o do envio">
+                {"✅ Íntegro"}
+            </span>
+        },
+        Some(Integrity::Mismatch) => html! {
+            <span class="badge-mismatch" title="O SHA-256 do download não bate com o do envio">
+                {"⚠️ Hash diferente"}
+            </span>
+        },
+        _ => html! {},
+    }
+}
@@ -1,4 +1,17 @@
+pub mod download_progress;
+pub mod drop_zone;
 pub mod error_banner;
+pub mod expired_list;
 pub mod file_card;
 pub mod file_list;
+pub mod file_preview;
+pub mod integrity_badge;
+pub mod nav_bar;
+pub mod offline_banner;
+pub mod owner_actions;
+pub mod password_prompt;
+pub mod qr_modal;
+pub mod toasts;
+pub mod token_lock_settings;
 pub mod upload_form;
+pub mod upload_queue;
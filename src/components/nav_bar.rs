@@ -0,0 +1,32 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::router::Route;
+use crate::store::files_store::FilesStoreContext;
+
+#[function_component(NavBar)]
+pub fn nav_bar() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let current = use_route::<Route>();
+
+    // O destinatário de um link não precisa da navegação do app
+    if matches!(current, Some(Route::Share { .. })) {
+        return html! {};
+    }
+
+    let link = |route: Route, label: String| {
+        let active = current.as_ref() == Some(&route);
+        html! {
+            <Link<Route> to={route} classes={classes!("nav-link", active.then_some("active"))}>
+                {label}
+            </Link<Route>>
+        }
+    };
+
+    html! {
+        <nav class="nav-bar">
+            { link(Route::Home, "📤 Enviar".to_string()) }
+            { link(Route::History, format!("🗂️ Meus compartilhamentos ({})", store.files.len())) }
+            { link(Route::Settings, "⚙️ Configurações".to_string()) }
+        </nav>
+    }
+}
@@ -0,0 +1,20 @@
+use yew::prelude::*;
+use crate::store::files_store::FilesStoreContext;
+
+#[function_component(OfflineBanner)]
+pub fn offline_banner() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    if store.online {
+        return html! {};
+    }
+
+    html! {
+        <div class="offline-banner">
+            <strong>{"📴 Você está offline."}</strong>
+            <span>
+                {" O histórico continua disponível. Novos envios ficam na fila e começam sozinhos quando a conexão voltar."}
+            </span>
+        </div>
+    }
+}
@@ -0,0 +1,132 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use crate::hooks::use_api::use_api;
+use crate::models::error::ApiError;
+use crate::models::file::{FileUpdated, StoredFile};
+use crate::models::toast::ToastKind;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::constants::{DEFAULT_EXPIRES_IN, DOWNLOAD_LIMIT_CHOICES, EXPIRY_CHOICES};
+use crate::utils::formatters::format_download_limit;
+
+#[derive(Properties, PartialEq)]
+pub struct OwnerActionsProps {
+    pub file: StoredFile,
+}
+
+// Ações que exigem o token de dono: estender validade e mudar limite de downloads
+#[function_component(OwnerActions)]
+pub fn owner_actions(props: &OwnerActionsProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let api = use_api();
+    let expires_in = use_state(|| props.file.expires_in.unwrap_or(DEFAULT_EXPIRES_IN));
+    let max_downloads = use_state(|| props.file.max_downloads);
+    let busy = use_state(|| false);
+
+    let Some(token) = props.file.deletion_token.clone() else {
+        return html! {};
+    };
+
+    // Aplica a resposta do servidor ao arquivo salvo e avisa o resultado
+    let apply = {
+        let store = store.clone();
+        let file = props.file.clone();
+        let busy = busy.clone();
+        Callback::from(move |(result, expires_in, success): (Result<FileUpdated, ApiError>, Option<u64>, &'static str)| {
+            busy.set(false);
+            match result {
+                Ok(updated) => {
+                    let mut file = file.clone();
+                    file.expires_at = updated.expires_at;
+                    file.expires_in = expires_in.or(file.expires_in);
+                    file.max_downloads = updated.max_downloads;
+                    store.dispatch(FilesStoreAction::UpdateFile(file));
+                    store.dispatch(FilesStoreAction::ShowToast(ToastKind::Success, success.to_string()));
+                }
+                Err(e) => {
+                    store.dispatch(FilesStoreAction::ShowToast(
+                        ToastKind::Error,
+                        format!("Não foi possível alterar o compartilhamento: {}", e),
+                    ));
+                }
+            }
+        })
+    };
+
+    let on_expiry_change = {
+        let expires_in = expires_in.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(secs) = select.value().parse::<u64>() {
+                expires_in.set(secs);
+            }
+        })
+    };
+
+    let on_limit_change = {
+        let max_downloads = max_downloads.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            max_downloads.set(select.value().parse::<u32>().ok());
+        })
+    };
+
+    let on_extend = {
+        let api = api.clone();
+        let file_id = props.file.file_id.clone();
+        let token = token.clone();
+        let expires_in = expires_in.clone();
+        let busy = busy.clone();
+        let apply = apply.clone();
+        Callback::from(move |_| {
+            let (api, file_id, token, apply) = (api.clone(), file_id.clone(), token.clone(), apply.clone());
+            let secs = *expires_in;
+            busy.set(true);
+            spawn_local(async move {
+                let result = api.extend_expiry(&file_id, &token, secs).await;
+                apply.emit((result, Some(secs), "⏰ Validade estendida"));
+            });
+        })
+    };
+
+    let on_set_limit = {
+        let file_id = props.file.file_id.clone();
+        let max_downloads = max_downloads.clone();
+        let busy = busy.clone();
+        Callback::from(move |_| {
+            let (api, file_id, token, apply) = (api.clone(), file_id.clone(), token.clone(), apply.clone());
+            let limit = *max_downloads;
+            busy.set(true);
+            spawn_local(async move {
+                let result = api.set_download_limit(&file_id, &token, limit).await;
+                apply.emit((result, None, "⬇️ Limite de downloads alterado"));
+            });
+        })
+    };
+
+    html! {
+        <div class="owner-actions">
+            <div class="owner-action">
+                <span>{"⏰ Nova validade, a partir de agora"}</span>
+                <select onchange={on_expiry_change} disabled={*busy}>
+                    { for EXPIRY_CHOICES.iter().map(|(secs, label)| html! {
+                        <option value={secs.to_string()} selected={*secs == *expires_in}>{*label}</option>
+                    }) }
+                </select>
+                <button onclick={on_extend} class="btn-remove" type="button" disabled={*busy}>{"Aplicar"}</button>
+            </div>
+            <div class="owner-action">
+                <span>{"⬇️ Limite de downloads"}</span>
+                <select onchange={on_limit_change} disabled={*busy}>
+                    <option value="" selected={max_downloads.is_none()}>{format_download_limit(None)}</option>
+                    { for DOWNLOAD_LIMIT_CHOICES.iter().map(|n| html! {
+                        <option value={n.to_string()} selected={*max_downloads == Some(*n)}>
+                            {format_download_limit(Some(*n))}
+                        </option>
+                    }) }
+                </select>
+                <button onclick={on_set_limit} class="btn-remove" type="button" disabled={*busy}>{"Aplicar"}</button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,61 @@
+use yew::prelude::*;
+use web_sys::{HtmlInputElement, SubmitEvent};
+
+#[derive(Properties, PartialEq)]
+pub struct PasswordPromptProps {
+    pub on_submit: Callback<String>,
+    pub on_cancel: Callback<()>,
+    #[prop_or_default]
+    pub wrong_password: bool,
+}
+
+// Pede a senha de um arquivo protegido antes do download
+#[function_component(PasswordPrompt)]
+pub fn password_prompt(props: &PasswordPromptProps) -> Html {
+    let input_ref = use_node_ref();
+
+    {
+        let input_ref = input_ref.clone();
+        use_effect_with((), move |_| {
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let _ = input.focus();
+            }
+            || ()
+        });
+    }
+
+    let on_submit = {
+        let input_ref = input_ref.clone();
+        let on_submit = props.on_submit.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if let Some(input) = input_ref.cast::<HtmlInputElement>() {
+                let password = input.value();
+                if !password.is_empty() {
+                    on_submit.emit(password);
+                }
+            }
+        })
+    };
+
+    let on_cancel = {
+        let on_cancel = props.on_cancel.clone();
+        Callback::from(move |_| on_cancel.emit(()))
+    };
+
+    html! {
+        <form class={classes!("password-prompt", props.wrong_password.then_some("wrong"))} onsubmit={on_submit}>
+            <input
+                ref={input_ref}
+                type="password"
+                placeholder="Senha do arquivo"
+                autocomplete="off"
+            />
+            <button type="submit" class="btn-download">{"🔓 Desbloquear"}</button>
+            <button type="button" class="btn-copy" onclick={on_cancel}>{"Cancelar"}</button>
+            if props.wrong_password {
+                <span class="password-error">{"🔑 Senha incorreta. Tente novamente."}</span>
+            }
+        </form>
+    }
+}
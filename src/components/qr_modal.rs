@@ -0,0 +1,97 @@
+use gloo::events::EventListener;
+use wasm_bindgen::JsCast;
+use web_sys::KeyboardEvent;
+use yew::prelude::*;
+use crate::models::error::ApiError;
+use crate::models::toast::ToastKind;
+use crate::services::qr::QrService;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::download::{save_text, save_url};
+
+#[derive(Properties, PartialEq)]
+pub struct QrModalProps {
+    pub url: String,
+    pub filename: String,
+    pub on_close: Callback<()>,
+}
+
+// QR code do link em tamanho grande, para abrir no celular
+#[function_component(QrModal)]
+pub fn qr_modal(props: &QrModalProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let svg = use_memo(props.url.clone(), |url| QrService::svg(url));
+
+    // Esc fecha o modal
+    {
+        let on_close = props.on_close.clone();
+        use_effect_with((), move |_| {
+            let window = web_sys::window().expect("window not found");
+            let listener = EventListener::new(&window, "keydown", move |e| {
+                if e.dyn_ref::<KeyboardEvent>().is_some_and(|e| e.key() == "Escape") {
+                    on_close.emit(());
+                }
+            });
+            move || drop(listener)
+        });
+    }
+
+    let report = {
+        let store = store.clone();
+        Callback::from(move |result: Result<(), ApiError>| {
+            if let Err(e) = result {
+                store.dispatch(FilesStoreAction::ShowToast(ToastKind::Error, e.to_string()));
+            }
+        })
+    };
+
+    let base_name = format!("{}-qr", props.filename);
+
+    let on_download_svg = {
+        let svg = svg.clone();
+        let report = report.clone();
+        let filename = format!("{}.svg", base_name);
+        Callback::from(move |_: MouseEvent| {
+            if let Ok(svg) = &*svg {
+                report.emit(save_text(svg, "image/svg+xml", &filename));
+            }
+        })
+    };
+
+    let on_download_png = {
+        let url = props.url.clone();
+        let filename = format!("{}.png", base_name);
+        Callback::from(move |_: MouseEvent| {
+            report.emit(QrService::png_data_url(&url, 12).and_then(|data| save_url(&data, &filename)));
+        })
+    };
+
+    let on_backdrop = props.on_close.reform(|_: MouseEvent| ());
+    let on_close = props.on_close.reform(|_: MouseEvent| ());
+    let stop = Callback::from(|e: MouseEvent| e.stop_propagation());
+
+    html! {
+        <div class="modal-backdrop" onclick={on_backdrop}>
+            <div class="modal qr-modal" role="dialog" aria-modal="true" onclick={stop}>
+                <div class="modal-header">
+                    <h2>{"📱 Abrir no celular"}</h2>
+                    <button onclick={on_close} class="modal-close" type="button" title="Fechar">{"✕"}</button>
+                </div>
+                {
+                    match &*svg {
+                        Ok(svg) => html! {
+                            <div class="qr-code">
+                                { Html::from_html_unchecked(AttrValue::from(svg.clone())) }
+                            </div>
+                        },
+                        Err(e) => html! { <div class="download-error">{"❌ "}{e.to_string()}</div> },
+                    }
+                }
+                <p class="qr-caption">{&props.filename}</p>
+                <div class="qr-actions">
+                    <button onclick={on_download_png} class="btn-copy" type="button">{"⬇️ PNG"}</button>
+                    <button onclick={on_download_svg} class="btn-copy" type="button">{"⬇️ SVG"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
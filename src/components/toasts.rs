@@ -0,0 +1,62 @@
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+use crate::models::toast::{Toast, ToastKind};
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::constants::{TOAST_DURATION_MS, UNDO_DURATION_MS};
+
+#[function_component(Toasts)]
+pub fn toasts() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    html! {
+        <div class="toasts" aria-live="polite">
+            { for store.toasts.iter().map(|toast| html! { <ToastView key={toast.id} toast={toast.clone()} /> }) }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ToastViewProps {
+    toast: Toast,
+}
+
+#[function_component(ToastView)]
+fn toast_view(props: &ToastViewProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let id = props.toast.id;
+
+    // Fechar o aviso (ou deixá-lo expirar) confirma a remoção; só então
+    // `use_pending_deletions` exclui os arquivos marcados no servidor
+    let on_close = {
+        let store = store.clone();
+        Callback::from(move |_: ()| store.dispatch(FilesStoreAction::DismissToast(id)))
+    };
+
+    // Some sozinho depois de alguns segundos
+    {
+        let on_close = on_close.clone();
+        let duration = if props.toast.undo.is_some() { UNDO_DURATION_MS } else { TOAST_DURATION_MS };
+        use_effect_with(id, move |_| {
+            let timeout = Timeout::new(duration, move || on_close.emit(()));
+            move || drop(timeout)
+        });
+    }
+
+    let on_dismiss = on_close.reform(|_: MouseEvent| ());
+    let on_undo = Callback::from(move |_: MouseEvent| store.dispatch(FilesStoreAction::UndoRemoval(id)));
+
+    let class = match props.toast.kind {
+        ToastKind::Success => "toast-success",
+        ToastKind::Error => "toast-error",
+    };
+
+    html! {
+        <div class={classes!("toast", class)} role="status">
+            <span>{&props.toast.message}</span>
+            if props.toast.undo.is_some() {
+                <button onclick={on_undo} class="toast-action" type="button">{"Desfazer"}</button>
+            }
+            <button onclick={on_dismiss} class="toast-close" type="button" title="Fechar">{"✕"}</button>
+        </div>
+    }
+}
@@ -0,0 +1,104 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use crate::models::error::ApiError;
+use crate::models::toast::ToastKind;
+use crate::services::crypto::CryptoService;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+
+// Frase-senha que cifra os tokens de dono guardados neste navegador
+#[function_component(TokenLockSettings)]
+pub fn token_lock_settings() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let passphrase = use_state(String::new);
+    let error = use_state(|| None::<ApiError>);
+
+    let on_input = {
+        let passphrase = passphrase.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            passphrase.set(input.value());
+        })
+    };
+
+    // A derivação Argon2id leva um instante; a página fica parada enquanto isso
+    let on_submit = {
+        let store = store.clone();
+        let passphrase = passphrase.clone();
+        let error = error.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            if passphrase.is_empty() {
+                return;
+            }
+
+            let result = match &store.settings.token_lock {
+                None => CryptoService::create_token_lock(&passphrase).map(|(key, lock)| {
+                    (FilesStoreAction::EnableTokenLock(lock, key), "🔒 Tokens de dono protegidos")
+                }),
+                Some(lock) => CryptoService::unlock_token_lock(&passphrase, lock)
+                    .map(|key| (FilesStoreAction::UnlockTokens(key), "🔓 Tokens de dono desbloqueados")),
+            };
+
+            match result {
+                Ok((action, message)) => {
+                    store.dispatch(action);
+                    store.dispatch(FilesStoreAction::ShowToast(ToastKind::Success, message.to_string()));
+                    passphrase.set(String::new());
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e)),
+            }
+        })
+    };
+
+    let on_lock = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(FilesStoreAction::LockTokens))
+    };
+
+    let on_disable = {
+        let store = store.clone();
+        Callback::from(move |_| store.dispatch(FilesStoreAction::DisableTokenLock))
+    };
+
+    let enabled = store.settings.token_lock.is_some();
+    let unlocked = store.owner_key.is_some();
+
+    let hint = match (enabled, unlocked) {
+        (false, _) => "Os tokens que permitem excluir, estender ou limitar seus compartilhamentos ficam em texto puro neste navegador. Defina uma frase-senha para cifrá-los.",
+        (true, false) => "Bloqueado: digite a frase-senha para excluir, estender ou limitar seus compartilhamentos. Tokens de novos envios ficam só nesta aba até você desbloquear.",
+        (true, true) => "Desbloqueado nesta aba. Os tokens continuam cifrados no armazenamento do navegador.",
+    };
+
+    html! {
+        <div class="settings-row settings-row-column">
+            <div>
+                <div class="settings-label">{"Proteger tokens de dono"}</div>
+                <div class="settings-hint">{hint}</div>
+            </div>
+            if enabled && unlocked {
+                <div class="token-lock-actions">
+                    <button onclick={on_lock} class="btn-remove" type="button">{"🔒 Bloquear agora"}</button>
+                    <button onclick={on_disable} class="btn-remove btn-danger" type="button">{"Remover proteção"}</button>
+                </div>
+            } else {
+                <form class="token-lock-form" onsubmit={on_submit}>
+                    <input
+                        type="password"
+                        class="upload-password"
+                        placeholder="Frase-senha"
+                        autocomplete={if enabled { "current-password" } else { "new-password" }}
+                        value={(*passphrase).clone()}
+                        oninput={on_input}
+                    />
+                    <button type="submit" class="btn-remove" disabled={passphrase.is_empty()}>
+                        { if enabled { "🔓 Desbloquear" } else { "🔒 Proteger" } }
+                    </button>
+                </form>
+                if let Some(e) = &*error {
+                    <div class="download-error">{"❌ "}{e.to_string()}</div>
+                }
+            }
+        </div>
+    }
+}
@@ -1,93 +1,222 @@
 use yew::prelude::*;
-use web_sys::{Event, File, HtmlInputElement};
-use wasm_bindgen::JsCast;
+use web_sys::{Event, File, HtmlInputElement, HtmlSelectElement};
+use crate::components::upload_queue::UploadQueue;
 use crate::hooks::use_file_upload::use_file_upload;
-use crate::store::files_store::FilesStoreContext;
-use crate::utils::formatters::format_bytes;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::constants::{DOWNLOAD_LIMIT_CHOICES, EXPIRY_CHOICES};
+use crate::utils::formatters::{format_bytes, format_download_limit};
 
 #[function_component(UploadForm)]
 pub fn upload_form() -> Html {
-    let selected_file = use_state(|| None::<File>);
+    let selected_files = use_state(Vec::<File>::new);
     let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
-    let loading = store.loading; // usa o loading do store
     let upload = use_file_upload();
+    let options = store.upload_options.clone();
 
-    // Quando o usuário seleciona um arquivo
+    // Quando o usuário seleciona um ou mais arquivos
     let on_file_change = {
-        let selected_file = selected_file.clone();
+        let selected_files = selected_files.clone();
         Callback::from(move |e: Event| {
-            let input: HtmlInputElement = e.target().unwrap().dyn_into().unwrap();
-            if let Some(files) = input.files() {
-                if let Some(file) = files.get(0) {
-                    selected_file.set(Some(file));
-                    return;
-                }
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut files = (*selected_files).clone();
+            if let Some(list) = input.files() {
+                files.extend((0..list.length()).filter_map(|i| list.get(i)));
             }
-            selected_file.set(None);
+            // Permite selecionar o mesmo arquivo novamente depois
+            input.set_value("");
+            selected_files.set(files);
         })
     };
 
-    // Ao clicar em upload
+    // Ao clicar em upload: todos os arquivos vão para a fila
     let on_upload = {
-        let selected_file = selected_file.clone();
+        let selected_files = selected_files.clone();
         let upload = upload.clone();
         Callback::from(move |_| {
-            if let Some(file) = (*selected_file).clone() {
-                upload.emit(file); // dispara o hook de upload
-                selected_file.set(None); // limpa seleção
+            upload.emit((*selected_files).clone());
+            selected_files.set(Vec::new()); // limpa seleção
+        })
+    };
+
+    // Remover um arquivo da seleção
+    let on_remove = {
+        let selected_files = selected_files.clone();
+        Callback::from(move |index: usize| {
+            let mut files = (*selected_files).clone();
+            if index < files.len() {
+                files.remove(index);
             }
+            selected_files.set(files);
         })
     };
 
-    // Limpar seleção de arquivo
-    let on_clear = {
-        let selected_file = selected_file.clone();
-        Callback::from(move |_| {
-            selected_file.set(None);
+    // Opções valem para todos os arquivos, inclusive os arrastados ou colados
+    let on_encrypt_change = {
+        let store = store.clone();
+        let options = options.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut options = options.clone();
+            options.encrypt = input.checked();
+            store.dispatch(FilesStoreAction::SetUploadOptions(options));
+        })
+    };
+
+    let on_password_toggle = {
+        let store = store.clone();
+        let options = options.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut options = options.clone();
+            options.password = input.checked().then(String::new);
+            store.dispatch(FilesStoreAction::SetUploadOptions(options));
+        })
+    };
+
+    let on_password_input = {
+        let store = store.clone();
+        let options = options.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut options = options.clone();
+            options.password = Some(input.value());
+            store.dispatch(FilesStoreAction::SetUploadOptions(options));
+        })
+    };
+
+    let on_expiry_change = {
+        let store = store.clone();
+        let options = options.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(expires_in) = select.value().parse::<u64>() {
+                let mut options = options.clone();
+                options.policy.expires_in = expires_in;
+                store.dispatch(FilesStoreAction::SetUploadOptions(options));
+            }
+        })
+    };
+
+    // Valor vazio = sem limite
+    let on_limit_change = {
+        let store = store.clone();
+        let options = options.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let mut options = options.clone();
+            options.policy.max_downloads = select.value().parse::<u32>().ok();
+            store.dispatch(FilesStoreAction::SetUploadOptions(options));
         })
     };
 
+    // Com a proteção por senha ligada, só envia depois de a senha ser digitada
+    let missing_password = options.password.as_deref() == Some("");
+
+    let total_size: u64 = selected_files.iter().map(|f| f.size() as u64).sum();
+
     html! {
         <div class="upload-section">
             <div class="file-input-wrapper">
                 <input
                     type="file"
                     id="file-input"
+                    multiple=true
                     onchange={on_file_change}
-                    disabled={loading}
                 />
                 <label for="file-input" class="file-label">
-                    {"🔍 Escolher arquivo"}
+                    {"🔍 Escolher arquivos"}
                 </label>
+                <p class="drop-hint">{"ou arraste e solte em qualquer lugar da página, ou cole com Ctrl+V"}</p>
             </div>
 
-            // Spinner e mensagem enquanto estiver carregando
-            if loading {
-                <div class="upload-loading">
-                    <div class="spinner"></div>
-                    <span>{"Enviando arquivo..."}</span>
+            <div class="upload-options">
+                <div class="upload-policy">
+                    <label class="upload-option">
+                        <span>{"⏰ Validade"}</span>
+                        <select onchange={on_expiry_change}>
+                            { for EXPIRY_CHOICES.iter().map(|(secs, label)| html! {
+                                <option value={secs.to_string()} selected={*secs == options.policy.expires_in}>
+                                    {*label}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
+                    <label class="upload-option">
+                        <span>{"⬇️ Downloads"}</span>
+                        <select onchange={on_limit_change}>
+                            <option value="" selected={options.policy.max_downloads.is_none()}>
+                                {format_download_limit(None)}
+                            </option>
+                            { for DOWNLOAD_LIMIT_CHOICES.iter().map(|n| html! {
+                                <option value={n.to_string()} selected={options.policy.max_downloads == Some(*n)}>
+                                    {format_download_limit(Some(*n))}
+                                </option>
+                            }) }
+                        </select>
+                    </label>
                 </div>
-            }
+                <label class="upload-option">
+                    <input type="checkbox" checked={options.encrypt} onchange={on_encrypt_change} />
+                    <span>{"🔒 Criptografar no navegador (ponta a ponta)"}</span>
+                </label>
+                if options.encrypt && options.password.is_none() {
+                    <p class="upload-option-hint">
+                        {"A chave fica só no link compartilhado; o servidor recebe apenas dados cifrados."}
+                    </p>
+                }
+                <label class="upload-option">
+                    <input type="checkbox" checked={options.password.is_some()} onchange={on_password_toggle} />
+                    <span>{"🔑 Proteger com senha"}</span>
+                </label>
+                if let Some(password) = &options.password {
+                    <input
+                        type="password"
+                        class="upload-password"
+                        placeholder="Senha que o destinatário vai digitar"
+                        autocomplete="new-password"
+                        value={password.clone()}
+                        oninput={on_password_input}
+                    />
+                    <p class="upload-option-hint">
+                        {"O arquivo é cifrado com uma chave derivada da senha. Sem ela, ninguém abre o arquivo."}
+                    </p>
+                }
+            </div>
 
-            if let Some(file) = (*selected_file).as_ref() {
-                <div class="selected-file">
-                    <span class="file-name">{file.name()}</span>
-                    <span class="file-size">{format_bytes(file.size() as u64)}</span>
-                    <button onclick={on_clear} class="btn-clear" disabled={loading}>
-                        {"✕"}
-                    </button>
-                </div>
+            {
+                for selected_files.iter().enumerate().map(|(index, file)| {
+                    let on_remove = on_remove.clone();
+                    html! {
+                        <div class="selected-file">
+                            <span class="file-name">{file.name()}</span>
+                            <span class="file-size">{format_bytes(file.size() as u64)}</span>
+                            <button onclick={move |_| on_remove.emit(index)} class="btn-clear">
+                                {"✕"}
+                            </button>
+                        </div>
+                    }
+                })
+            }
 
-                if !loading {
-                    <button
-                        onclick={on_upload}
-                        class="btn-upload"
-                        disabled={loading}
-                    >
-                        {"⬆️ Fazer Upload"}
-                    </button>
-                }
+            if !selected_files.is_empty() {
+                <button
+                    onclick={on_upload}
+                    class="btn-upload"
+                    disabled={missing_password}
+                >
+                    {
+                        if !store.online {
+                            "📴 Enfileirar para quando voltar a conexão".to_string()
+                        } else if selected_files.len() == 1 {
+                            "⬆️ Fazer Upload".to_string()
+                        } else {
+                            format!("⬆️ Enviar {} arquivos ({})", selected_files.len(), format_bytes(total_size))
+                        }
+                    }
+                </button>
             }
+
+            <UploadQueue />
         </div>
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,164 @@
+use yew::prelude::*;
+use crate::models::operation::Operation;
+use crate::models::upload::{UploadItem, UploadStatus};
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::constants::MAX_UPLOAD_ATTEMPTS;
+use crate::utils::formatters::{format_bytes, format_duration, format_throughput};
+
+#[function_component(UploadQueue)]
+pub fn upload_queue() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    if store.uploads.is_empty() {
+        return html! {};
+    }
+
+    let has_finished = store.uploads.iter().any(|u| !u.status.is_active());
+
+    let on_clear_finished = {
+        let store = store.clone();
+        Callback::from(move |_| {
+            store.dispatch(FilesStoreAction::ClearFinishedUploads);
+        })
+    };
+
+    let on_cancel = {
+        let store = store.clone();
+        Callback::from(move |id: u32| {
+            store.dispatch(FilesStoreAction::CancelOperation(Operation::Upload(id)));
+        })
+    };
+
+    let on_retry = {
+        let store = store.clone();
+        Callback::from(move |id: u32| {
+            store.dispatch(FilesStoreAction::RetryUpload(id));
+        })
+    };
+
+    let on_discard = {
+        let store = store.clone();
+        Callback::from(move |id: u32| {
+            store.dispatch(FilesStoreAction::DiscardUpload(id));
+        })
+    };
+
+    let actions = RowActions { cancel: on_cancel, retry: on_retry, discard: on_discard };
+    let done = store.uploads.iter().filter(|u| u.status == UploadStatus::Done).count();
+    let pending = store.uploads.iter().any(|u| u.status.is_active());
+
+    html! {
+        <div class="upload-queue">
+            <div class="upload-queue-header">
+                <span>{format!("Fila de envio ({}/{})", done, store.uploads.len())}</span>
+                if has_finished {
+                    <button onclick={on_clear_finished} class="btn-clear-finished" type="button">
+                        {"Limpar concluídos"}
+                    </button>
+                }
+            </div>
+            if pending {
+                <p class="upload-queue-hint">
+                    {"A fila fica salva neste navegador: recarregar a página ou perder a conexão não perde os arquivos (exceto envios com senha)."}
+                </p>
+            }
+            { for store.uploads.iter().map(|item| upload_row(item, store.online, &actions)) }
+        </div>
+    }
+}
+
+struct RowActions {
+    cancel: Callback<u32>,
+    retry: Callback<u32>,
+    discard: Callback<u32>,
+}
+
+fn upload_row(item: &UploadItem, online: bool, actions: &RowActions) -> Html {
+    let (class, label) = match &item.status {
+        UploadStatus::Queued if !online => ("queued", "📴 Aguardando conexão".to_string()),
+        UploadStatus::Queued => ("queued", "⏳ Na fila".to_string()),
+        UploadStatus::Encrypting => ("uploading", "🔒 Criptografando...".to_string()),
+        UploadStatus::Hashing => ("uploading", "🧮 Calculando SHA-256...".to_string()),
+        UploadStatus::Uploading => ("uploading", "⬆️ Enviando...".to_string()),
+        UploadStatus::Done => ("done", "✅ Concluído".to_string()),
+        UploadStatus::Failed(e) => ("failed", format!("❌ {}", e)),
+        UploadStatus::Cancelled => ("cancelled", "🚫 Cancelado".to_string()),
+        UploadStatus::Retrying(at) => {
+            let time = js_sys::Date::new(&(*at).into()).to_locale_time_string("pt-BR");
+            (
+                "queued",
+                format!(
+                    "🔁 Nova tentativa às {} ({}/{})",
+                    time, item.attempts, MAX_UPLOAD_ATTEMPTS
+                ),
+            )
+        }
+    };
+
+    let id = item.id;
+    let on_cancel = actions.cancel.reform(move |_| id);
+    let on_retry = actions.retry.reform(move |_| id);
+    let on_discard = actions.discard.reform(move |_| id);
+
+    // Em espera ou encerrado com erro: o usuário decide o que fazer
+    let can_retry = matches!(
+        item.status,
+        UploadStatus::Retrying(_) | UploadStatus::Failed(_) | UploadStatus::Cancelled
+    );
+
+    html! {
+        <div key={item.id} class={classes!("upload-row", class)}>
+            <div class="upload-row-main">
+                <span class="file-name">{item.filename()}</span>
+                <span class="file-size">{format_bytes(item.size())}</span>
+                <span class="upload-status">{label}</span>
+                if can_retry {
+                    <button onclick={on_retry} class="btn-retry" type="button">
+                        { if matches!(item.status, UploadStatus::Retrying(_)) { "Tentar agora" } else { "Tentar de novo" } }
+                    </button>
+                    <button onclick={on_discard} class="btn-discard" type="button">
+                        {"Descartar"}
+                    </button>
+                } else if item.status.is_active() {
+                    <button onclick={on_cancel} class="btn-cancel" type="button" title="Cancelar envio">
+                        {"✕"}
+                    </button>
+                }
+            </div>
+            if item.status.is_running() {
+                { upload_progress(item) }
+            }
+        </div>
+    }
+}
+
+fn upload_progress(item: &UploadItem) -> Html {
+    let progress = item.progress.as_ref().filter(|_| item.status == UploadStatus::Uploading);
+    let Some(progress) = progress else {
+        return html! {
+            <div class="progress-bar indeterminate">
+                <div class="progress-fill"></div>
+            </div>
+        };
+    };
+
+    let percent = progress.percent();
+    let eta = progress
+        .eta_secs()
+        .map(|secs| format!("faltam {}", format_duration(secs)))
+        .unwrap_or_default();
+
+    html! {
+        <>
+            <div class="progress-bar">
+                <div class="progress-fill" style={format!("width: {:.1}%", percent)}></div>
+            </div>
+            <div class="progress-details">
+                <span>{format!("{:.0}%", percent)}</span>
+                <span>{format!("{} de {}", format_bytes(progress.loaded), format_bytes(progress.total))}</span>
+                <span>{format_throughput(progress.throughput())}</span>
+                <span>{eta}</span>
+            </div>
+        </>
+    }
+}
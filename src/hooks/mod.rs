@@ -1,2 +1,7 @@
+pub mod use_api;
 pub mod use_clipboard;
-pub mod use_file_upload;
\ No newline at end of file
+pub mod use_file_upload;
+pub mod use_online_status;
+pub mod use_pending_deletions;
+pub mod use_share;
+pub mod use_upload_queue;
@@ -0,0 +1,9 @@
+use yew::prelude::*;
+use crate::models::config::AppConfig;
+use crate::services::api::ApiClient;
+
+#[hook]
+pub fn use_api() -> ApiClient {
+    let config = use_context::<AppConfig>().expect("AppConfig not found");
+    ApiClient::new(&config)
+}
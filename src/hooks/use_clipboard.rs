@@ -1,17 +1,22 @@
 use yew::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::window;
+use crate::models::toast::ToastKind;
+use crate::services::share::ShareService;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
 
+// Copia o texto e avisa o usuário se deu certo ou não
 #[hook]
 pub fn use_clipboard() -> Callback<String> {
-    Callback::from(|text: String| {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    Callback::from(move |text: String| {
+        let store = store.clone();
         spawn_local(async move {
-            if let Some(window) = window() {
-                let navigator = window.navigator().clipboard();
-                let _ = wasm_bindgen_futures::JsFuture::from(
-                    navigator.write_text(&text)
-                ).await;
-            }
+            let toast = match ShareService::copy_text(&text).await {
+                Ok(()) => (ToastKind::Success, "📋 Copiado para a área de transferência".to_string()),
+                Err(e) => (ToastKind::Error, format!("Não foi possível copiar: {}", e)),
+            };
+            store.dispatch(FilesStoreAction::ShowToast(toast.0, toast.1));
         });
     })
-}
\ No newline at end of file
+}
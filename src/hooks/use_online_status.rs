@@ -0,0 +1,25 @@
+use gloo::events::EventListener;
+use yew::prelude::*;
+use crate::store::files_store::{FilesStore, FilesStoreAction};
+
+// Mantém `store.online` em dia com os eventos online/offline do navegador.
+// Deve ser chamado uma única vez, no componente que cria o store.
+#[hook]
+pub fn use_online_status(store: UseReducerHandle<FilesStore>) {
+    use_effect_with((), move |_| {
+        let window = web_sys::window().expect("window not found");
+
+        let on_online = {
+            let store = store.clone();
+            EventListener::new(&window, "online", move |_| {
+                store.dispatch(FilesStoreAction::SetOnline(true));
+            })
+        };
+
+        let on_offline = EventListener::new(&window, "offline", move |_| {
+            store.dispatch(FilesStoreAction::SetOnline(false));
+        });
+
+        move || drop((on_online, on_offline))
+    });
+}
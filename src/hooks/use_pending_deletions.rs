@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use crate::models::error::ApiError;
+use crate::models::file::StoredFile;
+use crate::models::toast::ToastKind;
+use crate::services::api::ApiClient;
+use crate::store::files_store::{FilesStore, FilesStoreAction};
+
+// Exclui no servidor os arquivos em `pending_deletions` cujo aviso de
+// "Desfazer" já sumiu, incluindo os que ficaram pendentes quando a página foi
+// fechada. Sem conexão a exclusão espera; com o bloqueio ligado, espera o
+// desbloqueio. Deve ser chamado uma única vez, no componente que cria o store.
+#[hook]
+pub fn use_pending_deletions(store: UseReducerHandle<FilesStore>, api: ApiClient) {
+    let in_flight = use_mut_ref(HashSet::<String>::new);
+
+    let ready: Vec<StoredFile> = store
+        .pending_deletions
+        .iter()
+        .filter(|f| f.deletion_token.is_some() && !awaiting_undo(&store, &f.file_id))
+        .cloned()
+        .collect();
+    let online = store.online;
+
+    use_effect_with((ready, online), move |(ready, online)| {
+        for file in ready.iter().filter(|_| *online).cloned() {
+            let Some(token) = file.deletion_token.clone() else {
+                continue;
+            };
+            if !in_flight.borrow_mut().insert(file.file_id.clone()) {
+                continue;
+            }
+            let api = api.clone();
+            let store = store.clone();
+            let in_flight = in_flight.clone();
+            spawn_local(async move {
+                let result = api.delete_file(&file.file_id, &token).await;
+                in_flight.borrow_mut().remove(&file.file_id);
+                match result {
+                    Ok(()) | Err(ApiError::Gone) => {
+                        store.dispatch(FilesStoreAction::FinishDeletion(file.file_id));
+                    }
+                    // Continua pendente até a conexão voltar ou a página abrir de novo
+                    Err(ApiError::NetworkError(_)) => {}
+                    Err(e) => {
+                        let message = format!(
+                            "Não foi possível excluir “{}” do servidor: {}. Ele voltou para a lista.",
+                            file.filename, e
+                        );
+                        store.dispatch(FilesStoreAction::RestoreFiles(vec![(0, file)]));
+                        store.dispatch(FilesStoreAction::ShowToast(ToastKind::Error, message));
+                    }
+                }
+            });
+        }
+        || ()
+    });
+}
+
+// Ainda dá para desfazer a remoção pelo aviso
+fn awaiting_undo(store: &FilesStore, file_id: &str) -> bool {
+    store
+        .toasts
+        .iter()
+        .filter_map(|t| t.undo.as_ref())
+        .any(|r| r.files.iter().any(|(_, f)| f.file_id == file_id))
+}
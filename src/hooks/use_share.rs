@@ -0,0 +1,33 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use crate::models::error::ApiError;
+use crate::models::toast::ToastKind;
+use crate::services::share::ShareService;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+
+// Abre o menu de compartilhamento do sistema com (título, link);
+// onde ele não existe ou falha, copia o link. Em todos os casos avisa
+// o usuário do que aconteceu
+#[hook]
+pub fn use_share() -> Callback<(String, String)> {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    Callback::from(move |(title, url): (String, String)| {
+        let store = store.clone();
+        spawn_local(async move {
+            let toast = match ShareService::share(&title, &url).await {
+                Ok(()) => (ToastKind::Success, "📤 Link compartilhado".to_string()),
+                // O usuário fechou o menu: nada a avisar
+                Err(ApiError::Aborted) => return,
+                Err(_) => match ShareService::copy_text(&url).await {
+                    Ok(()) => (
+                        ToastKind::Success,
+                        "Não foi possível compartilhar; o link foi copiado para a área de transferência".to_string(),
+                    ),
+                    Err(e) => (ToastKind::Error, format!("Não foi possível compartilhar nem copiar: {}", e)),
+                },
+            };
+            store.dispatch(FilesStoreAction::ShowToast(toast.0, toast.1));
+        });
+    })
+}
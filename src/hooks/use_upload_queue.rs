@@ -0,0 +1,162 @@
+use gloo::timers::callback::Timeout;
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{AbortController, AbortSignal};
+use crate::models::error::ApiError;
+use crate::models::file::StoredFile;
+use crate::models::upload::{ServerCapabilities, UploadItem, UploadStatus};
+use crate::services::api::ApiClient;
+use crate::services::crypto::CryptoService;
+use crate::services::hash::HashService;
+use crate::store::files_store::{FilesStore, FilesStoreAction};
+use crate::utils::constants::{DEFAULT_CHUNK_SIZE, MAX_CHUNK_SIZE};
+
+// Consome a fila de upload respeitando o limite de envios simultâneos.
+// Deve ser chamado uma única vez, no componente que cria o store.
+#[hook]
+pub fn use_upload_queue(store: UseReducerHandle<FilesStore>, api: ApiClient) {
+    let uploads = store.uploads.clone();
+    let max_concurrent = store.settings.max_concurrent_uploads;
+    let online = store.online;
+    // Sem saber se o backend aceita upload em partes, um item restaurado da
+    // caixa de saída iria num POST só e não retomaria a sessão de partes
+    let ready = online && store.capabilities_loaded;
+
+    use_effect_with((uploads, max_concurrent, ready), move |(uploads, max_concurrent, ready)| {
+        let active = store.active_uploads();
+        // Offline nada começa; os itens seguem na fila até a conexão voltar
+        let free_slots = if *ready { max_concurrent.saturating_sub(active) } else { 0 };
+
+        let next: Vec<UploadItem> = uploads
+            .iter()
+            .filter(|u| u.status == UploadStatus::Queued)
+            .take(free_slots)
+            .cloned()
+            .collect();
+
+        for item in next {
+            let Ok(controller) = AbortController::new() else {
+                continue;
+            };
+            let signal = controller.signal();
+            store.dispatch(FilesStoreAction::StartUpload(item.id, controller));
+            upload_item(item, signal, store.capabilities.clone(), api.clone(), store.clone());
+        }
+
+        // Acorda a fila quando vencer a próxima nova tentativa agendada
+        let next_retry = uploads
+            .iter()
+            .filter_map(|u| match u.status {
+                UploadStatus::Retrying(at) => Some(at),
+                _ => None,
+            })
+            .reduce(f64::min);
+        let timeout = next_retry.map(|at| {
+            let delay = (at - js_sys::Date::now()).max(0.0) as u32;
+            Timeout::new(delay, move || store.dispatch(FilesStoreAction::WakeRetries))
+        });
+
+        move || drop(timeout)
+    });
+}
+
+fn upload_item(
+    item: UploadItem,
+    signal: AbortSignal,
+    capabilities: ServerCapabilities,
+    api: ApiClient,
+    store: UseReducerHandle<FilesStore>,
+) {
+    spawn_local(async move {
+        let id = item.id;
+
+        match send_item(item, &signal, &capabilities, &api, &store).await {
+            Ok(stored_file) => {
+                store.dispatch(FilesStoreAction::AddFile(stored_file));
+                store.dispatch(FilesStoreAction::SetUploadStatus(id, UploadStatus::Done));
+            }
+            // O status já foi marcado como cancelado por CancelOperation
+            Err(ApiError::Aborted) => {}
+            // Falhas temporárias voltam para a fila com espera exponencial
+            Err(e) => {
+                store.dispatch(FilesStoreAction::UploadFailed(id, e));
+            }
+        }
+    });
+}
+
+async fn send_item(
+    item: UploadItem,
+    signal: &AbortSignal,
+    capabilities: &ServerCapabilities,
+    api: &ApiClient,
+    store: &UseReducerHandle<FilesStore>,
+) -> Result<StoredFile, ApiError> {
+    let filename = item.filename();
+    let mut file = item.file.clone();
+    let mut encryption_key = None;
+
+    let password_protected = item.options.password.is_some();
+
+    if item.options.encrypt || password_protected {
+        store.dispatch(FilesStoreAction::SetUploadStatus(item.id, UploadStatus::Encrypting));
+        match &item.options.password {
+            Some(password) => {
+                file = CryptoService::encrypt_file_with_password(&file, password).await?;
+            }
+            None => {
+                let (encrypted, key) = CryptoService::encrypt_file(&file).await?;
+                file = encrypted;
+                encryption_key = Some(key);
+            }
+        }
+
+    }
+
+    // Hash do que vai para o servidor, para conferir no download
+    store.dispatch(FilesStoreAction::SetUploadStatus(item.id, UploadStatus::Hashing));
+    let sha256 = HashService::sha256(&file).await?;
+    if signal.aborted() {
+        return Err(ApiError::Aborted);
+    }
+    store.dispatch(FilesStoreAction::SetUploadStatus(item.id, UploadStatus::Uploading));
+
+    let on_progress = {
+        let store = store.clone();
+        let id = item.id;
+        Callback::from(move |(loaded, total): (u64, u64)| {
+            store.dispatch(FilesStoreAction::SetUploadProgress(id, loaded, total));
+        })
+    };
+
+    // Upload em partes só quando o backend anuncia suporte. Um tamanho 0 (ou
+    // absurdo) vindo do servidor não pode derrubar o app
+    let chunk_size = capabilities
+        .chunk_size
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_CHUNK_SIZE)
+        .min(MAX_CHUNK_SIZE);
+    let response = if capabilities.chunked_upload && file.size() as u64 > chunk_size {
+        let resumable = !(item.options.encrypt || password_protected);
+        api.upload_file_chunked(file, chunk_size, &item.options.policy, &sha256, resumable, on_progress, signal)
+            .await?
+    } else {
+        api.upload_file(file, &item.options.policy, &sha256, on_progress, signal).await?
+    };
+
+    Ok(StoredFile {
+        file_id: response.file_id,
+        filename,
+        download_url: response.download_url,
+        expires_at: response.expires_at,
+        uploaded_at: js_sys::Date::new_0().to_iso_string().into(),
+        encryption_key,
+        password_protected,
+        stale: false,
+        expires_in: response.expires_in.or(Some(item.options.policy.expires_in)),
+        max_downloads: response.max_downloads.or(item.options.policy.max_downloads),
+        deletion_token: response.deletion_token,
+        sealed_token: None,
+        sha256: Some(sha256),
+    })
+}
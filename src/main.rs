@@ -2,6 +2,8 @@ mod app;
 mod components;
 mod hooks;
 mod models;
+mod pages;
+mod router;
 mod services;
 mod store;
 mod utils;
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use crate::utils::constants::{DEFAULT_API_URL, FALLBACK_API_URL};
+
+// Configuração lida de /config.json no boot; permite apontar a mesma
+// imagem para backends diferentes (produção, staging, on-prem)
+#[derive(Clone, PartialEq, Deserialize, Debug)]
+pub struct AppConfig {
+    pub api_url: String,
+}
+
+// O padrão de build passa pela mesma validação do config.json; um API_URL
+// inválido no build cai no backend público
+impl Default for AppConfig {
+    fn default() -> Self {
+        let built_in = Self {
+            api_url: DEFAULT_API_URL.to_string(),
+        };
+        built_in.validate().unwrap_or_else(|_| Self {
+            api_url: FALLBACK_API_URL.to_string(),
+        })
+    }
+}
+
+impl AppConfig {
+    pub fn validate(mut self) -> Result<Self, String> {
+        let api_url = self.api_url.trim().trim_end_matches('/');
+
+        if !(api_url.starts_with("http://") || api_url.starts_with("https://")) {
+            return Err(format!("api_url inválida: {:?}", self.api_url));
+        }
+
+        self.api_url = api_url.to_string();
+        Ok(self)
+    }
+}
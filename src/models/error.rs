@@ -1,4 +1,5 @@
 use std::fmt;
+use crate::utils::formatters::format_bytes;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ApiError {
@@ -8,6 +9,22 @@ pub enum ApiError {
     NetworkError(String),
     JsonParse(String),
     ServerError(u16),
+    NotFound,
+    Forbidden,
+    Gone,
+    IntegrityMismatch,
+    PreviewTooLarge(u64),
+    UnknownFileType,
+    Aborted,
+    Encryption(String),
+    InvalidKey,
+    CorruptedCiphertext,
+    DecryptionFailed,
+    WrongPassword,
+    FileRead(String),
+    Storage(String),
+    BrowserApi(String),
+    QrCode(String),
 }
 
 impl fmt::Display for ApiError {
@@ -19,6 +36,59 @@ impl fmt::Display for ApiError {
             ApiError::NetworkError(e) => write!(f, "Erro de rede: {}", e),
             ApiError::JsonParse(e) => write!(f, "Erro ao processar resposta: {}", e),
             ApiError::ServerError(code) => write!(f, "Erro no servidor (código {})", code),
+            ApiError::NotFound => {
+                write!(f, "Arquivo não encontrado: o link expirou ou o arquivo foi removido")
+            }
+            ApiError::IntegrityMismatch => {
+                write!(f, "O conteúdo recebido não confere com o SHA-256 do envio")
+            }
+            ApiError::PreviewTooLarge(limit) => write!(
+                f,
+                "Arquivo grande demais para pré-visualizar (limite de {})",
+                format_bytes(*limit)
+            ),
+            ApiError::UnknownFileType => {
+                write!(f, "Não há pré-visualização para este tipo de arquivo")
+            }
+            ApiError::Forbidden => write!(f, "Acesso negado pelo servidor"),
+            ApiError::Gone => {
+                write!(f, "Arquivo indisponível: expirou ou atingiu o limite de downloads")
+            }
+            ApiError::Aborted => write!(f, "Operação cancelada"),
+            ApiError::Encryption(e) => write!(f, "Erro ao criptografar: {}", e),
+            ApiError::InvalidKey => write!(f, "Chave de descriptografia inválida"),
+            ApiError::CorruptedCiphertext => {
+                write!(f, "Arquivo criptografado corrompido ou incompleto")
+            }
+            ApiError::DecryptionFailed => {
+                write!(f, "Falha ao descriptografar: o arquivo foi alterado ou a chave está errada")
+            }
+            ApiError::WrongPassword => write!(f, "Senha incorreta"),
+            ApiError::FileRead(e) => write!(f, "Erro ao ler arquivo: {}", e),
+            ApiError::Storage(e) => write!(f, "Erro no armazenamento local: {}", e),
+            ApiError::BrowserApi(e) => write!(f, "Recurso do navegador indisponível: {}", e),
+            ApiError::QrCode(e) => write!(f, "Erro ao gerar QR code: {}", e),
+        }
+    }
+}
+
+impl ApiError {
+    // Status HTTP de erro com significado próprio para o app
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            404 => ApiError::NotFound,
+            401 | 403 => ApiError::Forbidden,
+            410 => ApiError::Gone,
+            _ => ApiError::ServerError(status),
+        }
+    }
+
+    // Falhas que podem passar sozinhas (conexão, servidor sobrecarregado)
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ApiError::NetworkError(_) => true,
+            ApiError::ServerError(code) => matches!(code, 408 | 429) || *code >= 500,
+            _ => false,
         }
     }
 }
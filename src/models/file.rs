@@ -7,6 +7,30 @@ pub struct StoredFile {
     pub download_url: String,
     pub expires_at: String,
     pub uploaded_at: String,
+    #[serde(default)]
+    pub encryption_key: Option<String>, // chave AES codificada; só existe em uploads criptografados
+    #[serde(default)]
+    pub password_protected: bool,
+    #[serde(default)]
+    pub stale: bool, // não foi possível confirmar com o servidor na última verificação
+    #[serde(default)]
+    pub expires_in: Option<u64>, // validade escolhida no envio, em segundos
+    #[serde(default)]
+    pub max_downloads: Option<u32>,
+    #[serde(default)]
+    pub deletion_token: Option<String>, // prova de dono: excluir, estender validade, mudar limite
+    #[serde(default)]
+    pub sealed_token: Option<String>, // o mesmo token cifrado, quando protegido por frase-senha
+    #[serde(default)]
+    pub sha256: Option<String>, // hash do conteúdo enviado (já criptografado, se for o caso)
+}
+
+// Resultado da verificação de um arquivo salvo
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FileStatus {
+    Alive,   // o servidor ainda tem o arquivo
+    Gone,    // expirou ou foi removido (404/410)
+    Unknown, // servidor fora do ar, sem conexão ou resposta inesperada
 }
 
 #[derive(Clone, PartialEq, Deserialize, Debug)]
@@ -14,4 +38,57 @@ pub struct UploadResponse {
     pub file_id: String,
     pub download_url: String,
     pub expires_at: String,
-}
\ No newline at end of file
+    // Política aplicada pelo servidor, se ele devolver
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+    #[serde(default)]
+    pub max_downloads: Option<u32>,
+    #[serde(default, alias = "owner_token")]
+    pub deletion_token: Option<String>,
+}
+
+// Resposta de PATCH /files/{file_id}
+#[derive(Clone, PartialEq, Deserialize, Debug)]
+pub struct FileUpdated {
+    pub expires_at: String,
+    #[serde(default)]
+    pub max_downloads: Option<u32>,
+}
+
+// Metadados públicos de um arquivo (GET /files/{file_id}), usados na página do destinatário
+#[derive(Clone, PartialEq, Deserialize, Debug)]
+pub struct FileInfo {
+    pub file_id: String,
+    pub filename: String,
+    pub size: u64,
+    pub expires_at: String,
+    #[serde(default)]
+    pub max_downloads: Option<u32>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+// Conferência do SHA-256 no download
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Integrity {
+    Verified,  // o conteúdo recebido bate com o hash do envio
+    Mismatch,  // não bate: o arquivo não foi salvo
+    Unchecked, // o envio não tem hash registrado
+}
+
+// Download interrompido guardado no IndexedDB; `validator` é o ETag (forte) ou
+// o Last-Modified da resposta, usado no If-Range para retomar com segurança
+#[derive(Clone, PartialEq, Debug)]
+pub struct PartialDownload {
+    pub file_id: String,
+    pub blob: web_sys::Blob,
+    pub validator: String,
+    pub total: u64,
+    pub expires_at: String,
+}
+
+impl PartialDownload {
+    pub fn received(&self) -> u64 {
+        self.blob.size() as u64
+    }
+}
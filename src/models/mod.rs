@@ -1,2 +1,7 @@
+pub mod config;
 pub mod error;
 pub mod file;
+pub mod operation;
+pub mod settings;
+pub mod toast;
+pub mod upload;
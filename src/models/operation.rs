@@ -0,0 +1,6 @@
+// Identifica uma operação de rede em andamento que pode ser cancelada
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Operation {
+    Upload(u32),      // id do item na fila de upload
+    Download(String), // file_id
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use crate::utils::constants::DEFAULT_MAX_CONCURRENT_UPLOADS;
+
+// Preferências do usuário, salvas no localStorage
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+#[serde(default)]
+pub struct Settings {
+    pub max_concurrent_uploads: usize,
+    pub encrypt_by_default: bool,
+    pub token_lock: Option<TokenLock>, // tokens de dono cifrados no localStorage
+}
+
+// Salt e verificador da frase-senha que protege os tokens de dono
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct TokenLock {
+    pub salt: String,
+    pub verifier: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_concurrent_uploads: DEFAULT_MAX_CONCURRENT_UPLOADS,
+            encrypt_by_default: false,
+            token_lock: None,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::models::file::StoredFile;
+
+// Avisos rápidos exibidos no canto da tela
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ToastKind {
+    Success,
+    Error,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Toast {
+    pub id: u32,
+    pub kind: ToastKind,
+    pub message: String,
+    pub undo: Option<Removal>, // avisos de remoção oferecem "Desfazer"
+}
+
+// Arquivos tirados da lista, com a posição original para poder desfazer.
+// A exclusão no servidor só acontece quando o aviso some sem "Desfazer".
+#[derive(Clone, PartialEq, Debug)]
+pub struct Removal {
+    pub files: Vec<(usize, StoredFile)>,
+    pub delete_on_server: bool,
+}
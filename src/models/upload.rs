@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+use web_sys::File;
+use crate::utils::constants::DEFAULT_EXPIRES_IN;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum UploadStatus {
+    Queued,
+    Encrypting,
+    Hashing,
+    Uploading,
+    Done,
+    Failed(String),
+    Cancelled,
+    Retrying(f64), // falha temporária; nova tentativa neste horário (Date.now)
+}
+
+impl UploadStatus {
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            UploadStatus::Queued
+                | UploadStatus::Encrypting
+                | UploadStatus::Hashing
+                | UploadStatus::Uploading
+                | UploadStatus::Retrying(_)
+        )
+    }
+
+    // Ocupa uma das vagas de envio simultâneo
+    pub fn is_running(&self) -> bool {
+        matches!(
+            self,
+            UploadStatus::Encrypting | UploadStatus::Hashing | UploadStatus::Uploading
+        )
+    }
+}
+
+// Validade e limite de downloads pedidos ao servidor no envio
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct UploadPolicy {
+    pub expires_in: u64,             // segundos
+    pub max_downloads: Option<u32>, // Some(1) = apagar após o primeiro download
+}
+
+impl Default for UploadPolicy {
+    fn default() -> Self {
+        Self {
+            expires_in: DEFAULT_EXPIRES_IN,
+            max_downloads: None,
+        }
+    }
+}
+
+// Opções escolhidas no formulário, aplicadas a cada arquivo ao entrar na fila
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct UploadOptions {
+    pub encrypt: bool,
+    pub password: Option<String>, // com senha, a chave é derivada dela em vez de aleatória
+    pub policy: UploadPolicy,
+}
+
+// Um item da fila de upload (um por arquivo selecionado)
+#[derive(Clone, PartialEq, Debug)]
+pub struct UploadItem {
+    pub id: u32,
+    pub file: File,
+    pub status: UploadStatus,
+    pub progress: Option<TransferProgress>,
+    pub started_at: Option<f64>, // quando o envio em si começou (status Uploading), em ms
+    pub options: UploadOptions,
+    pub key: String,   // chave na caixa de saída (IndexedDB)
+    pub attempts: u32, // falhas até agora
+}
+
+impl UploadItem {
+    // A taxa e o tempo restante contam a partir da entrada em Uploading, não
+    // do primeiro evento de progresso (criptografia e hash ficam de fora)
+    pub fn set_status(&mut self, status: UploadStatus) {
+        if status == UploadStatus::Uploading && self.status != UploadStatus::Uploading {
+            self.started_at = Some(js_sys::Date::now());
+            self.progress = None;
+        }
+        self.status = status;
+    }
+
+    pub fn filename(&self) -> String {
+        self.file.name()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.file.size() as u64
+    }
+}
+
+// Um envio salvo na caixa de saída, restaurado ao abrir o app
+#[derive(Clone, PartialEq, Debug)]
+pub struct OutboxRecord {
+    pub key: String,
+    pub file: File,
+    pub encrypt: bool,
+    pub policy: UploadPolicy,
+    pub attempts: u32,
+    pub error: Option<String>,
+}
+
+// Progresso de um upload ou download em andamento; tempos em milissegundos
+// (Date.now). `total` é 0 quando o servidor não informa o tamanho. O primeiro
+// evento de cada transferência diz quanto já existia de uma tentativa anterior
+// (`resumed`); esses bytes não entram na taxa nem no tempo restante
+#[derive(Clone, PartialEq, Debug)]
+pub struct TransferProgress {
+    pub loaded: u64,
+    pub total: u64,
+    pub resumed: u64,
+    pub started_at: f64,
+    pub updated_at: f64,
+}
+
+impl TransferProgress {
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        (self.loaded as f64 / self.total as f64 * 100.0).min(100.0)
+    }
+
+    // Bytes por segundo desde o início desta tentativa
+    pub fn throughput(&self) -> f64 {
+        let elapsed = (self.updated_at - self.started_at) / 1000.0;
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+        self.loaded.saturating_sub(self.resumed) as f64 / elapsed
+    }
+
+    // Tempo restante estimado, em segundos
+    pub fn eta_secs(&self) -> Option<f64> {
+        let throughput = self.throughput();
+        if throughput <= 0.0 {
+            return None;
+        }
+        Some(self.total.saturating_sub(self.loaded) as f64 / throughput)
+    }
+}
+
+// Recursos anunciados pelo backend em GET /capabilities
+#[derive(Clone, PartialEq, Deserialize, Debug, Default)]
+pub struct ServerCapabilities {
+    #[serde(default)]
+    pub chunked_upload: bool,
+    #[serde(default)]
+    pub chunk_size: Option<u64>,
+}
+
+// Estado de um upload em partes, salvo no localStorage para poder
+// ser retomado depois de uma queda de conexão ou recarga da página
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
+pub struct UploadSession {
+    pub fingerprint: String, // nome + tamanho + data de modificação + política
+    pub upload_id: String,
+    pub chunk_size: u64,
+    pub completed_chunks: Vec<u32>,
+    #[serde(default)]
+    pub created_at: f64, // Date.now(); sessões antigas são descartadas
+}
+
+#[derive(Serialize)]
+pub struct ChunkedUploadInit<'a> {
+    pub filename: &'a str,
+    pub size: u64,
+    pub chunk_size: u64,
+    pub expires_in: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_downloads: Option<u32>,
+    pub sha256: &'a str,
+}
+
+#[derive(Deserialize)]
+pub struct ChunkedUploadCreated {
+    pub upload_id: String,
+}
+
+#[derive(Deserialize)]
+pub struct ChunkedUploadState {
+    pub received_chunks: Vec<u32>,
+}
@@ -0,0 +1,71 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::components::file_card::FileCard;
+use crate::router::Route;
+use crate::store::files_store::FilesStoreContext;
+use crate::utils::formatters::{format_datetime, format_download_limit, format_expiry};
+use crate::utils::links::share_url;
+
+#[derive(Properties, PartialEq)]
+pub struct FileDetailsPageProps {
+    pub file_id: String,
+}
+
+// Detalhes de um compartilhamento feito por este navegador
+#[function_component(FileDetailsPage)]
+pub fn file_details_page(props: &FileDetailsPageProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    let Some(file) = store.files.iter().find(|f| f.file_id == props.file_id) else {
+        return html! {
+            <div class="empty-state">
+                <h2>{"Compartilhamento não encontrado"}</h2>
+                <p>{"Ele não está no histórico deste navegador ou já expirou."}</p>
+                <Link<Route> to={Route::History} classes="btn-download">{"Ver meus compartilhamentos"}</Link<Route>>
+            </div>
+        };
+    };
+
+    let link = share_url(file);
+
+    html! {
+        <div class="files-list">
+            <Link<Route> to={Route::History} classes="back-link">{"← Meus compartilhamentos"}</Link<Route>>
+            <h2>{&file.filename}</h2>
+            <FileCard file={file.clone()} />
+            <dl class="file-details">
+                <dt>{"ID"}</dt>
+                <dd>{&file.file_id}</dd>
+                <dt>{"Enviado em"}</dt>
+                <dd>{format_datetime(&file.uploaded_at)}</dd>
+                <dt>{"Expira em"}</dt>
+                <dd>
+                    {format_datetime(&file.expires_at)}
+                    if let Some(expires_in) = file.expires_in {
+                        {format!(" (validade de {})", format_expiry(expires_in))}
+                    }
+                </dd>
+                <dt>{"Downloads"}</dt>
+                <dd>{format_download_limit(file.max_downloads)}</dd>
+                <dt>{"Proteção"}</dt>
+                <dd>
+                    {
+                        if file.password_protected {
+                            "Protegido por senha"
+                        } else if file.encryption_key.is_some() {
+                            "Criptografado (chave no link)"
+                        } else {
+                            "Nenhuma"
+                        }
+                    }
+                </dd>
+                if let Some(sha256) = &file.sha256 {
+                    <dt>{"SHA-256"}</dt>
+                    <dd><code>{sha256}</code></dd>
+                }
+                <dt>{"Link"}</dt>
+                <dd><a href={link.clone()} class="share-link">{link}</a></dd>
+            </dl>
+        </div>
+    }
+}
@@ -0,0 +1,58 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::components::{expired_list::ExpiredList, file_list::FileList};
+use crate::router::Route;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+
+#[function_component(HistoryPage)]
+pub fn history_page() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let confirming = use_state(|| false);
+
+    let on_ask_clear = {
+        let confirming = confirming.clone();
+        Callback::from(move |_| confirming.set(true))
+    };
+
+    let on_cancel_clear = {
+        let confirming = confirming.clone();
+        Callback::from(move |_| confirming.set(false))
+    };
+
+    // Só limpa a lista deste navegador; os links continuam valendo
+    let on_confirm_clear = {
+        let store = store.clone();
+        let confirming = confirming.clone();
+        Callback::from(move |_| {
+            confirming.set(false);
+            store.dispatch(FilesStoreAction::ClearAll);
+        })
+    };
+
+    html! {
+        <>
+            if store.files.is_empty() {
+                <div class="empty-state">
+                    <p>{"Você ainda não compartilhou nenhum arquivo."}</p>
+                    <Link<Route> to={Route::Home} classes="btn-download">{"📤 Enviar arquivos"}</Link<Route>>
+                </div>
+            } else {
+                <div class="history-actions">
+                    if *confirming {
+                        <span>{format!("Remover os {} arquivos do histórico deste navegador?", store.files.len())}</span>
+                        <button onclick={on_confirm_clear} class="btn-remove btn-danger" type="button">
+                            {"Sim, limpar"}
+                        </button>
+                        <button onclick={on_cancel_clear} class="btn-remove" type="button">{"Cancelar"}</button>
+                    } else {
+                        <button onclick={on_ask_clear} class="btn-remove" type="button">
+                            {"🧹 Limpar histórico"}
+                        </button>
+                    }
+                </div>
+                <FileList />
+            }
+            <ExpiredList />
+        </>
+    }
+}
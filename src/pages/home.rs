@@ -0,0 +1,24 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::components::{drop_zone::DropZone, file_list::FileList, upload_form::UploadForm};
+use crate::router::Route;
+use crate::store::files_store::FilesStoreContext;
+use crate::utils::constants::RECENT_FILES_ON_HOME;
+
+#[function_component(HomePage)]
+pub fn home_page() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+
+    html! {
+        <>
+            <DropZone />
+            <UploadForm />
+            <FileList title="Envios recentes" limit={RECENT_FILES_ON_HOME} />
+            if store.files.len() > RECENT_FILES_ON_HOME {
+                <Link<Route> to={Route::History} classes="see-all-link">
+                    {format!("Ver todos os {} compartilhamentos →", store.files.len())}
+                </Link<Route>>
+            }
+        </>
+    }
+}
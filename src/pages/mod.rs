@@ -0,0 +1,7 @@
+pub mod file_details;
+pub mod history;
+pub mod home;
+pub mod not_found;
+pub mod settings;
+pub mod share;
+pub mod share_target;
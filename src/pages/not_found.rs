@@ -0,0 +1,14 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::router::Route;
+
+#[function_component(NotFoundPage)]
+pub fn not_found_page() -> Html {
+    html! {
+        <div class="empty-state">
+            <h2>{"Página não encontrada"}</h2>
+            <p>{"O endereço acessado não existe."}</p>
+            <Link<Route> to={Route::Home} classes="btn-download">{"Voltar ao início"}</Link<Route>>
+        </div>
+    }
+}
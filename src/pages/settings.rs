@@ -0,0 +1,69 @@
+use yew::prelude::*;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use crate::components::token_lock_settings::TokenLockSettings;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+
+#[function_component(SettingsPage)]
+pub fn settings_page() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let settings = store.settings.clone();
+
+    let on_concurrency_change = {
+        let store = store.clone();
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            if let Ok(max) = select.value().parse::<usize>() {
+                let mut settings = settings.clone();
+                settings.max_concurrent_uploads = max;
+                store.dispatch(FilesStoreAction::UpdateSettings(settings));
+            }
+        })
+    };
+
+    let on_encrypt_change = {
+        let store = store.clone();
+        let settings = settings.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut settings = settings.clone();
+            settings.encrypt_by_default = input.checked();
+
+            // Vale também para o formulário de upload atual
+            let mut options = store.upload_options.clone();
+            options.encrypt = settings.encrypt_by_default;
+            store.dispatch(FilesStoreAction::SetUploadOptions(options));
+            store.dispatch(FilesStoreAction::UpdateSettings(settings));
+        })
+    };
+
+    html! {
+        <div class="settings-page">
+            <h2>{"Configurações"}</h2>
+
+            <div class="settings-row">
+                <div>
+                    <div class="settings-label">{"Envios simultâneos"}</div>
+                    <div class="settings-hint">{"Quantos arquivos da fila são enviados ao mesmo tempo."}</div>
+                </div>
+                <select onchange={on_concurrency_change}>
+                    { for (1..=5).map(|n| html! {
+                        <option value={n.to_string()} selected={n == settings.max_concurrent_uploads}>
+                            {n}
+                        </option>
+                    }) }
+                </select>
+            </div>
+
+            <div class="settings-row">
+                <div>
+                    <div class="settings-label">{"Criptografar por padrão"}</div>
+                    <div class="settings-hint">{"Deixa a criptografia ponta a ponta marcada ao abrir o app."}</div>
+                </div>
+                <input type="checkbox" checked={settings.encrypt_by_default} onchange={on_encrypt_change} />
+            </div>
+
+            <TokenLockSettings />
+        </div>
+    }
+}
@@ -0,0 +1,223 @@
+use gloo::timers::callback::Interval;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::components::download_progress::DownloadProgress;
+use crate::components::file_preview::FilePreview;
+use crate::components::integrity_badge::IntegrityBadge;
+use crate::components::password_prompt::PasswordPrompt;
+use crate::hooks::use_api::use_api;
+use crate::hooks::use_clipboard::use_clipboard;
+use crate::models::error::ApiError;
+use crate::models::file::{FileInfo, StoredFile};
+use crate::router::Route;
+use crate::store::files_store::{FilesStoreAction, FilesStoreContext};
+use crate::utils::formatters::{format_bytes, format_duration};
+use crate::utils::links::ShareSecret;
+
+#[derive(Properties, PartialEq)]
+pub struct SharePageProps {
+    pub file_id: String,
+}
+
+// Página que o destinatário abre pelo link: nome, tamanho, tempo restante e download
+#[function_component(SharePage)]
+pub fn share_page(props: &SharePageProps) -> Html {
+    let info = use_state(|| None::<Result<FileInfo, ApiError>>);
+    let api = use_api();
+    // Navegar entre links dentro do app troca o arquivo e o #fragmento sem
+    // remontar a página: o segredo acompanha os dois
+    let hash = use_location().map(|l| l.hash().to_string()).unwrap_or_default();
+    let secret = use_memo((props.file_id.clone(), hash), |(_, hash)| ShareSecret::parse(hash));
+
+    {
+        let info = info.clone();
+        let api = api.clone();
+        use_effect_with(props.file_id.clone(), move |file_id| {
+            info.set(None);
+            let file_id = file_id.clone();
+            spawn_local(async move {
+                info.set(Some(api.get_file_info(&file_id).await));
+            });
+            || ()
+        });
+    }
+
+    let content = match &*info {
+        None => html! {
+            <div class="upload-loading">
+                <div class="spinner"></div>
+                <span>{"Carregando arquivo..."}</span>
+            </div>
+        },
+        Some(Err(ApiError::NotFound | ApiError::Gone)) => html! {
+            <div class="share-missing">
+                <h2>{"Arquivo não encontrado"}</h2>
+                <p>{"O link expirou, o arquivo foi removido ou o endereço está incorreto."}</p>
+            </div>
+        },
+        Some(Err(e)) => html! {
+            <div class="error-message">{"❌ "}{e.to_string()}</div>
+        },
+        Some(Ok(info)) => html! {
+            <ShareDetails key={info.file_id.clone()} info={info.clone()} secret={(*secret).clone()} />
+        },
+    };
+
+    html! {
+        <div class="share-page">
+            { content }
+            <Link<Route> to={Route::Home} classes="share-home-link">
+                {"📤 Enviar meus próprios arquivos"}
+            </Link<Route>>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ShareDetailsProps {
+    info: FileInfo,
+    secret: ShareSecret,
+}
+
+#[function_component(ShareDetails)]
+fn share_details(props: &ShareDetailsProps) -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    let api = use_api();
+    let now = use_state(js_sys::Date::now);
+    let prompting = use_state(|| false);
+
+    // Atualiza a contagem regressiva a cada segundo
+    {
+        let now = now.clone();
+        use_effect_with((), move |_| {
+            let interval = Interval::new(1000, move || now.set(js_sys::Date::now()));
+            move || drop(interval)
+        });
+    }
+
+    let info = &props.info;
+    let expires_at = js_sys::Date::parse(&info.expires_at);
+    let remaining = (expires_at - *now) / 1000.0;
+
+    // O download reaproveita o mesmo fluxo dos arquivos enviados por este navegador
+    let file = StoredFile {
+        file_id: info.file_id.clone(),
+        filename: info.filename.clone(),
+        download_url: api.download_url(&info.file_id),
+        expires_at: info.expires_at.clone(),
+        uploaded_at: String::new(),
+        encryption_key: props.secret.key.clone(),
+        password_protected: props.secret.password_protected,
+        stale: false,
+        expires_in: None,
+        max_downloads: info.max_downloads,
+        deletion_token: None,
+        sealed_token: None,
+        sha256: info.sha256.clone(),
+    };
+
+    let downloading = store.downloading_files.contains(&info.file_id);
+    let download_error = store.download_errors.get(&info.file_id);
+    let wrong_password = download_error == Some(&ApiError::WrongPassword);
+
+    let copy_to_clipboard = use_clipboard();
+    let on_copy_hash = {
+        let sha256 = info.sha256.clone().unwrap_or_default();
+        Callback::from(move |_: MouseEvent| copy_to_clipboard.emit(sha256.clone()))
+    };
+
+    let on_download = {
+        let api = api.clone();
+        let store = store.clone();
+        let file = file.clone();
+        let prompting = prompting.clone();
+        Callback::from(move |_: MouseEvent| {
+            if file.password_protected {
+                prompting.set(true);
+            } else {
+                api.download_file(file.clone(), None, store.clone());
+            }
+        })
+    };
+
+    let on_password = {
+        let api = api.clone();
+        let store = store.clone();
+        let file = file.clone();
+        let prompting = prompting.clone();
+        Callback::from(move |password: String| {
+            prompting.set(false);
+            api.download_file(file.clone(), Some(password), store.clone());
+        })
+    };
+
+    let on_password_cancel = {
+        let store = store.clone();
+        let file_id = info.file_id.clone();
+        let prompting = prompting.clone();
+        Callback::from(move |_| {
+            prompting.set(false);
+            store.dispatch(FilesStoreAction::SetDownloadError(file_id.clone(), None));
+        })
+    };
+
+    html! {
+        <div class="share-card">
+            <div class="share-icon">{"📄"}</div>
+            <h2 class="share-filename">{&info.filename}</h2>
+            <div class="file-meta">
+                <span>{"📦 "}{format_bytes(info.size)}</span>
+                if remaining > 0.0 {
+                    <span>{"⏰ Expira em "}{format_duration(remaining)}</span>
+                } else {
+                    <span>{"⏰ Expirado"}</span>
+                }
+                if file.encryption_key.is_some() {
+                    <span class="badge-encrypted">{"🔒 Criptografado"}</span>
+                }
+                if file.password_protected {
+                    <span class="badge-encrypted">{"🔑 Protegido por senha"}</span>
+                }
+                <IntegrityBadge file_id={info.file_id.clone()} />
+            </div>
+            if let Some(sha256) = &info.sha256 {
+                <div class="share-hash">
+                    <span class="share-hash-label">
+                        { if file.encryption_key.is_some() || file.password_protected {
+                            "SHA-256 do conteúdo criptografado (conferido automaticamente no download)"
+                        } else {
+                            "SHA-256 esperado (compare com `sha256sum` depois de baixar)"
+                        } }
+                    </span>
+                    <code>{sha256}</code>
+                    <button onclick={on_copy_hash} class="btn-copy" type="button">{"📋 Copiar"}</button>
+                </div>
+            }
+            if info.max_downloads == Some(1) {
+                <p class="share-warning">{"🔥 Este arquivo é apagado depois do primeiro download. Baixe só quando for guardar."}</p>
+            } else if let Some(n) = info.max_downloads {
+                <p class="share-warning">{format!("⬇️ Este link aceita no máximo {} downloads.", n)}</p>
+            }
+
+            <FilePreview file={file.clone()} />
+
+            if downloading {
+                <DownloadProgress file_id={info.file_id.clone()} />
+            } else if *prompting || wrong_password {
+                <PasswordPrompt
+                    on_submit={on_password}
+                    on_cancel={on_password_cancel}
+                    {wrong_password}
+                />
+            } else {
+                <button onclick={on_download} class="btn-upload" disabled={remaining <= 0.0}>
+                    {"⬇️ Baixar arquivo"}
+                </button>
+                if let Some(error) = download_error {
+                    <div class="download-error">{"❌ "}{error.to_string()}</div>
+                }
+            }
+        </div>
+    }
+}
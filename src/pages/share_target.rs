@@ -0,0 +1,123 @@
+use gloo::console;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::File;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::hooks::use_file_upload::use_file_upload;
+use crate::router::Route;
+use crate::services::idb::IdbService;
+use crate::store::files_store::FilesStoreContext;
+use crate::utils::constants::SHARE_INBOX_STORE;
+use crate::utils::formatters::format_bytes;
+
+// Destino do menu "Compartilhar" do sistema: o service worker guarda os
+// arquivos recebidos e esta página pede confirmação antes de enviá-los
+#[function_component(ShareTargetPage)]
+pub fn share_target_page() -> Html {
+    let store = use_context::<FilesStoreContext>().expect("FilesStoreContext not found");
+    // Arquivos recebidos, com as chaves no IndexedDB
+    let files = use_state(|| None::<Vec<(String, File)>>);
+    let upload = use_file_upload();
+    let navigator = use_navigator().expect("navigator not found");
+
+    {
+        let files = files.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let received = match IdbService::get_all_entries(SHARE_INBOX_STORE).await {
+                    Ok(entries) => entries
+                        .into_iter()
+                        .filter_map(|(key, value)| Some((key, value.dyn_into::<File>().ok()?)))
+                        .collect(),
+                    Err(e) => {
+                        console::warn!(format!("Falha ao ler arquivos compartilhados: {}", e));
+                        Vec::new()
+                    }
+                };
+                files.set(Some(received));
+            });
+            || ()
+        });
+    }
+
+    // Em qualquer saída saem da caixa de entrada os arquivos mostrados aqui;
+    // um compartilhamento que chegou enquanto isso fica para a próxima vez
+    let finish = {
+        let navigator = navigator.clone();
+        let files = files.clone();
+        Callback::from(move |_: ()| {
+            let keys: Vec<String> = (*files).iter().flatten().map(|(key, _)| key.clone()).collect();
+            spawn_local(async move {
+                if let Err(e) = IdbService::delete_keys(SHARE_INBOX_STORE, &keys).await {
+                    console::warn!(format!("Falha ao limpar arquivos compartilhados: {}", e));
+                }
+            });
+            navigator.replace(&Route::Home);
+        })
+    };
+
+    let on_confirm = {
+        let files = files.clone();
+        let finish = finish.clone();
+        Callback::from(move |_: MouseEvent| {
+            upload.emit((*files).iter().flatten().map(|(_, file)| file.clone()).collect());
+            finish.emit(());
+        })
+    };
+
+    let on_discard = finish.reform(|_: MouseEvent| ());
+
+    let Some(received) = &*files else {
+        return html! {
+            <div class="upload-loading">
+                <div class="spinner"></div>
+                <span>{"Carregando arquivos recebidos..."}</span>
+            </div>
+        };
+    };
+
+    if received.is_empty() {
+        return html! {
+            <div class="empty-state">
+                <h2>{"Nada para enviar"}</h2>
+                <p>{"Nenhum arquivo chegou pelo compartilhamento. Tente compartilhar de novo."}</p>
+                <Link<Route> to={Route::Home} classes="btn-download">{"Voltar ao início"}</Link<Route>>
+            </div>
+        };
+    }
+
+    let total_size: u64 = received.iter().map(|(_, f)| f.size() as u64).sum();
+    let options = &store.upload_options;
+    // O reducer recusaria o envio e a caixa de entrada seria esvaziada à toa
+    let missing_password = options.password.as_deref() == Some("");
+
+    html! {
+        <div class="share-target">
+            <h2>{format!("Enviar {} arquivo(s) para o Quickshare?", received.len())}</h2>
+            {
+                for received.iter().map(|(_, file)| html! {
+                    <div class="selected-file">
+                        <span class="file-name">{file.name()}</span>
+                        <span class="file-size">{format_bytes(file.size() as u64)}</span>
+                    </div>
+                })
+            }
+            <p class="settings-hint">
+                { if options.encrypt { "🔒 Os arquivos serão criptografados no navegador antes do envio." } else { "Os arquivos serão enviados sem criptografia." } }
+                {" Você pode mudar isso nas configurações."}
+            </p>
+            if missing_password {
+                <p class="settings-hint">{"🔑 A proteção por senha está ligada: digite a senha na página inicial antes de enviar."}</p>
+            }
+            <div class="share-target-actions">
+                <button onclick={on_confirm} class="btn-upload" disabled={missing_password}>
+                    {format!("⬆️ Enviar ({})", format_bytes(total_size))}
+                </button>
+                <button onclick={on_discard} class="btn-clear-finished" type="button">
+                    {"Descartar"}
+                </button>
+            </div>
+        </div>
+    }
+}
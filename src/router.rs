@@ -0,0 +1,41 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+use crate::pages::{
+    file_details::FileDetailsPage, history::HistoryPage, home::HomePage,
+    not_found::NotFoundPage, settings::SettingsPage, share::SharePage,
+    share_target::ShareTargetPage,
+};
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/historico")]
+    History,
+    // Detalhes de um arquivo enviado por este navegador
+    #[at("/arquivos/:file_id")]
+    FileDetails { file_id: String },
+    #[at("/configuracoes")]
+    Settings,
+    // Página que o destinatário abre pelo link compartilhado
+    #[at("/f/:file_id")]
+    Share { file_id: String },
+    // Arquivos recebidos do menu "Compartilhar" do sistema (share_target do manifest)
+    #[at("/compartilhar")]
+    ShareTarget,
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
+
+pub fn switch(route: Route) -> Html {
+    match route {
+        Route::Home => html! { <HomePage /> },
+        Route::History => html! { <HistoryPage /> },
+        Route::FileDetails { file_id } => html! { <FileDetailsPage {file_id} /> },
+        Route::Settings => html! { <SettingsPage /> },
+        Route::Share { file_id } => html! { <SharePage {file_id} /> },
+        Route::ShareTarget => html! { <ShareTargetPage /> },
+        Route::NotFound => html! { <NotFoundPage /> },
+    }
+}
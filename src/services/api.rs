@@ -1,83 +1,445 @@
 use crate::models::error::ApiError;
-use crate::models::file::UploadResponse;
-use crate::utils::constants::API_URL;
+use crate::models::file::{FileInfo, FileUpdated, UploadResponse};
+use crate::models::upload::{
+    ChunkedUploadCreated, ChunkedUploadInit, ChunkedUploadState, ServerCapabilities, UploadPolicy,
+    UploadSession,
+};
+use crate::services::crypto::{read_blob, CryptoService, PASSWORD_HEADER_LEN};
+use crate::services::hash::HashService;
+use crate::services::partial_download::PartialDownloadService;
+use crate::services::storage::StorageService;
+use crate::models::config::AppConfig;
+use crate::utils::constants::{
+    DOWNLOAD_CHECKPOINT_BYTES, DOWNLOAD_PROGRESS_INTERVAL_MS, MAX_CHUNK_RETRIES, MAX_CONCURRENT_VALIDATIONS,
+};
+use crate::utils::download::save_blob;
+use futures::{future, stream, StreamExt};
 use gloo_net::http::Request;
-use web_sys::{File, FormData};
+use gloo_timers::future::TimeoutFuture;
+use js_sys::Promise;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AbortController, AbortSignal, File, FormData, ProgressEvent, ReadableStreamDefaultReader,
+    XmlHttpRequest,
+};
 
 
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{Blob, Url};
+use web_sys::Blob;
 use wasm_bindgen::JsCast;
 use yew::prelude::*;
-use crate::models::file::StoredFile;
+use crate::models::file::{FileStatus, Integrity, StoredFile};
 use crate::store::files_store::{FilesStoreAction};
 
 
-pub struct ApiClient;
+// Cliente da API; a URL base vem da configuração carregada no boot
+#[derive(Clone, PartialEq, Debug)]
+pub struct ApiClient {
+    base_url: String,
+}
 
 impl ApiClient {
-
-    // Nova função para verificar se um arquivo existe
-    pub async fn check_file_exists(file_id: &str) -> bool {
-        let url = format!("{}/download/{}", API_URL, file_id);
-        
-        match Request::get(&url).send().await {
-            Ok(response) => response.ok(),
-            Err(_) => false,
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            base_url: config.api_url.clone(),
         }
     }
 
-    // Nova função para validar múltiplos arquivos
-    pub async fn validate_files(files: Vec<StoredFile>) -> Vec<StoredFile> {
-        let mut valid_files = Vec::new();
-        
-        for file in files {
-            if Self::check_file_exists(&file.file_id).await {
-                valid_files.push(file);
-            }
+    pub fn download_url(&self, file_id: &str) -> String {
+        format!("{}/download/{}", self.base_url, file_id)
+    }
+
+    // Verifica se um arquivo existe pelos metadados (GET /files/{id}), nunca
+    // pela rota de download: há backends que respondem HEAD rodando o GET, o
+    // que gastaria (ou apagaria) links com limite de downloads.
+    // Só 404/410 contam como removido; qualquer outra falha é inconclusiva
+    pub async fn check_file_status(&self, file_id: &str) -> FileStatus {
+        match self.get_file_info(file_id).await {
+            Ok(_) => FileStatus::Alive,
+            Err(ApiError::NotFound | ApiError::Gone) => FileStatus::Gone,
+            Err(_) => FileStatus::Unknown,
         }
-        
-        valid_files
+    }
+
+    // Valida vários arquivos em paralelo (com limite); cada resultado é
+    // emitido assim que chega
+    pub async fn validate_files(&self, files: Vec<StoredFile>, on_result: Callback<(String, FileStatus)>) {
+        stream::iter(files)
+            .map(|file| async move {
+                let status = self.check_file_status(&file.file_id).await;
+                (file.file_id, status)
+            })
+            .buffer_unordered(MAX_CONCURRENT_VALIDATIONS)
+            .for_each(|result| {
+                on_result.emit(result);
+                future::ready(())
+            })
+            .await;
     }
 
 
-    pub fn download_file(file: StoredFile, store: UseReducerHandle<crate::store::files_store::FilesStore>) {
+    // Dispara o download e registra andamento e erros no store, por file_id.
+    // `password` só é usado em arquivos protegidos por senha
+    pub fn download_file(
+        &self,
+        file: StoredFile,
+        password: Option<String>,
+        store: UseReducerHandle<crate::store::files_store::FilesStore>,
+    ) {
         let file_id = file.file_id.clone();
-        store.dispatch(FilesStoreAction::StartDownload(file_id.clone()));
+        let controller = match AbortController::new() {
+            Ok(controller) => controller,
+            Err(e) => {
+                let error = ApiError::BrowserApi(format!("{:?}", e));
+                store.dispatch(FilesStoreAction::SetDownloadError(file_id, Some(error)));
+                return;
+            }
+        };
+        let signal = controller.signal();
+        store.dispatch(FilesStoreAction::StartDownload(file_id.clone(), controller));
+
+        let on_progress = {
+            let store = store.clone();
+            let file_id = file_id.clone();
+            Callback::from(move |(loaded, total): (u64, u64)| {
+                store.dispatch(FilesStoreAction::SetDownloadProgress(file_id.clone(), loaded, total));
+            })
+        };
 
+        let api = self.clone();
         spawn_local(async move {
-            let download_url = format!("{}/download/{}", API_URL, file.file_id);
-            if let Ok(response) = Request::get(&download_url).send().await {
-                if let Ok(bytes) = response.binary().await {
-                    let u8_array = js_sys::Uint8Array::from(&bytes[..]);
-                    let blob = Blob::new_with_u8_array_sequence(&js_sys::Array::of1(&u8_array)).unwrap();
-                    let download_url = Url::create_object_url_with_blob(&blob).unwrap();
-
-                    let window = web_sys::window().unwrap();
-                    let document = window.document().unwrap();
-                    let a = document.create_element("a").unwrap();
-                    a.set_attribute("href", &download_url).unwrap();
-                    a.set_attribute("download", &file.filename).unwrap();
-                    a.dyn_ref::<web_sys::HtmlElement>().unwrap().click();
-                    Url::revoke_object_url(&download_url).unwrap();
+            match api.download(&file, password.as_deref(), &signal, &on_progress).await {
+                // Se foi cancelado, CancelOperation já limpou o estado
+                Err(ApiError::Aborted) => return,
+                Ok(Integrity::Unchecked) => {}
+                Ok(integrity) => {
+                    store.dispatch(FilesStoreAction::SetIntegrity(file_id.clone(), integrity));
+                }
+                Err(ApiError::IntegrityMismatch) => {
+                    store.dispatch(FilesStoreAction::SetIntegrity(file_id.clone(), Integrity::Mismatch));
+                    store.dispatch(FilesStoreAction::SetDownloadError(
+                        file_id.clone(),
+                        Some(ApiError::IntegrityMismatch),
+                    ));
+                }
+                Err(e) => {
+                    store.dispatch(FilesStoreAction::SetDownloadError(file_id.clone(), Some(e)));
                 }
             }
-
             store.dispatch(FilesStoreAction::EndDownload(file_id));
         });
     }
 
-    pub async fn upload_file(file: File) -> Result<UploadResponse, ApiError> {
+    // Baixa, descriptografa se preciso e entrega ao "salvar como" do navegador
+    pub async fn download(
+        &self,
+        file: &StoredFile,
+        password: Option<&str>,
+        signal: &AbortSignal,
+        on_progress: &Callback<(u64, u64)>,
+    ) -> Result<Integrity, ApiError> {
+        // Senha errada é descoberta pelo cabeçalho, antes de gastar um
+        // download em links com limite
+        if file.password_protected {
+            let password = password.ok_or(ApiError::WrongPassword)?;
+            let header = self.fetch_password_header(file, signal).await?;
+            CryptoService::check_password(&read_blob(&header).await?, password)?;
+        }
+
+        let blob = self.fetch_download(file, signal, on_progress).await?;
+
+        // O hash é do conteúdo como foi enviado, antes de descriptografar
+        let integrity = match &file.sha256 {
+            Some(expected) if HashService::matches(expected, &HashService::sha256(&blob).await?) => {
+                Integrity::Verified
+            }
+            Some(_) => {
+                PartialDownloadService::remove(&file.file_id).await;
+                return Err(ApiError::IntegrityMismatch);
+            }
+            None => Integrity::Unchecked,
+        };
+
+        // O parcial só sai depois de descriptografar e salvar: uma falha aqui
+        // não obriga a baixar (e contar) o arquivo de novo
+        let blob = decrypt_download(blob, file, password).await?;
+        save_blob(&blob, &file.filename)?;
+        PartialDownloadService::remove(&file.file_id).await;
+        Ok(integrity)
+    }
+
+    // Cabeçalho de um arquivo com senha: do parcial salvo, se houver, ou
+    // pedido com Range (só os primeiros bytes)
+    async fn fetch_password_header(&self, file: &StoredFile, signal: &AbortSignal) -> Result<Blob, ApiError> {
+        let len = PASSWORD_HEADER_LEN as u64;
+        if let Some(partial) = PartialDownloadService::load(&file.file_id).await
+            && partial.received() >= len
+        {
+            return partial
+                .blob
+                .slice_with_f64_and_f64(0.0, len as f64)
+                .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)));
+        }
+        self.fetch_prefix(&file.file_id, len, PASSWORD_HEADER_LEN, signal, |_| Ok(len))
+            .await
+    }
+
+    // Começo do arquivo em um único GET, para a pré-visualização. Os primeiros
+    // `sniff_len` bytes vão para `limit_for`, que decide quantos bytes ler no
+    // total (ou recusa); o resto da resposta é descartado
+    pub async fn fetch_prefix(
+        &self,
+        file_id: &str,
+        max_len: u64,
+        sniff_len: usize,
+        signal: &AbortSignal,
+        limit_for: impl FnOnce(&[u8]) -> Result<u64, ApiError>,
+    ) -> Result<Blob, ApiError> {
+        let url = self.download_url(file_id);
+        let response = Request::get(&url)
+            .abort_signal(Some(signal))
+            .header("Range", &format!("bytes=0-{}", max_len.saturating_sub(1)))
+            .send()
+            .await
+            .map_err(|e| request_error(e, signal))?;
+
+        if !response.ok() {
+            return Err(ApiError::from_status(response.status()));
+        }
+
+        let reader = body_reader(&response)?;
+        let parts = js_sys::Array::new();
+        let mut sample = Vec::with_capacity(sniff_len);
+        let mut limit_for = Some(limit_for);
+        let mut limit = max_len;
+        let mut loaded = 0u64;
+        let mut finished = false;
+        while loaded < limit {
+            let chunk = read_chunk(&reader).await?;
+            if let Some(chunk) = &chunk {
+                loaded += chunk.length() as u64;
+                parts.push(chunk);
+                if sample.len() < sniff_len {
+                    let take = (sniff_len - sample.len()).min(chunk.length() as usize);
+                    sample.extend(chunk.subarray(0, take as u32).to_vec());
+                }
+            }
+            finished = chunk.is_none();
+
+            // Amostra completa (ou arquivo menor que ela): hora de decidir
+            if (sample.len() >= sniff_len || finished)
+                && let Some(limit_for) = limit_for.take()
+            {
+                limit = limit_for(&sample)?.min(max_len);
+            }
+            if finished {
+                break;
+            }
+        }
+        if !finished {
+            let _ = reader.cancel();
+        }
+
+        Blob::new_with_blob_sequence(&parts)
+            .and_then(|blob| blob.slice_with_f64_and_f64(0.0, limit as f64))
+            .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))
+    }
+
+    // Arquivo inteiro já descriptografado com a chave do link, sem salvar
+    pub async fn fetch_decrypted(&self, file: &StoredFile, signal: &AbortSignal) -> Result<Blob, ApiError> {
+        let blob = self.fetch_download(file, signal, &Callback::noop()).await?;
+        let blob = decrypt_download(blob, file, None).await?;
+        PartialDownloadService::remove(&file.file_id).await;
+        Ok(blob)
+    }
+
+    // Baixa o conteúdo bruto, retomando de onde parou quando há um parcial
+    // salvo e o servidor aceita Range. O If-Range faz o servidor mandar o
+    // arquivo inteiro (200) se o ETag/Last-Modified mudou desde então
+    async fn fetch_download(
+        &self,
+        file: &StoredFile,
+        signal: &AbortSignal,
+        on_progress: &Callback<(u64, u64)>,
+    ) -> Result<Blob, ApiError> {
+        let url = self.download_url(&file.file_id);
+        let mut partial = PartialDownloadService::load(&file.file_id).await;
+
+        // Já veio inteiro numa tentativa anterior (que falhou depois, ao
+        // descriptografar ou salvar): nem precisa falar com o servidor
+        if let Some(complete) = partial.as_ref().filter(|p| p.total > 0 && p.received() == p.total) {
+            on_progress.emit((complete.total, complete.total));
+            return Ok(complete.blob.clone());
+        }
+
+        let (response, resumed) = loop {
+            let mut request = Request::get(&url).abort_signal(Some(signal));
+            if let Some(partial) = &partial {
+                request = request
+                    .header("Range", &format!("bytes={}-", partial.received()))
+                    .header("If-Range", &partial.validator);
+            }
+
+            let response = request.send().await.map_err(|e| request_error(e, signal))?;
+
+            match (response.status(), partial.take()) {
+                (206, Some(partial)) if content_range(&response).0 == Some(partial.received()) => {
+                    break (response, Some(partial));
+                }
+                // Trecho recusado ou fora do lugar: descarta o parcial e pede tudo
+                (206 | 416, Some(_)) => {
+                    PartialDownloadService::remove(&file.file_id).await;
+                }
+                (200, stale) => {
+                    if stale.is_some() {
+                        PartialDownloadService::remove(&file.file_id).await;
+                    }
+                    break (response, None);
+                }
+                (status, _) => {
+                    if matches!(status, 404 | 410) {
+                        PartialDownloadService::remove(&file.file_id).await;
+                    }
+                    return Err(ApiError::from_status(status));
+                }
+            }
+        };
+
+        // Só dá para retomar depois se o servidor aceita Range e identifica a versão
+        let headers = response.headers();
+        let validator = headers
+            .get("etag")
+            .filter(|etag| !etag.starts_with("W/"))
+            .or_else(|| headers.get("last-modified"));
+        let accepts_ranges = resumed.is_some()
+            || headers.get("accept-ranges").is_some_and(|v| v.eq_ignore_ascii_case("bytes"));
+        let validator = validator.filter(|_| accepts_ranges);
+
+        let offset = resumed.as_ref().map_or(0, |p| p.received());
+        let length = headers
+            .get("content-length")
+            .and_then(|len| len.parse::<u64>().ok())
+            .unwrap_or(0);
+        let total = match content_range(&response).1 {
+            Some(total) => total,
+            None if length > 0 => offset + length,
+            None => 0,
+        };
+
+        let resumable = validator.is_some();
+        if let (Some(validator), None) = (&validator, &resumed) {
+            PartialDownloadService::start(&file.file_id, validator, total, &file.expires_at).await;
+        }
+
+        let parts = js_sys::Array::new();
+        if let Some(partial) = &resumed {
+            parts.push(&partial.blob);
+        }
+
+        // Cada trecho é gravado uma vez só, e a leitura espera a gravação
+        // terminar: nada chega ao IndexedDB depois do `remove` de quem chamou
+        let on_checkpoint = async |segment: &js_sys::Array, start: u64| {
+            if resumable && let Ok(blob) = Blob::new_with_blob_sequence(segment) {
+                PartialDownloadService::append(&file.file_id, start, &blob).await;
+            }
+        };
+        read_stream(&response, offset, total, on_progress, &parts, on_checkpoint).await?;
+
+        // O parcial fica até quem chamou terminar de usar o conteúdo
+        Blob::new_with_blob_sequence(&parts).map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))
+    }
+
+    // Upload via XMLHttpRequest: `on_progress` recebe (bytes enviados, total)
+    pub async fn upload_file(
+        &self,
+        file: File,
+        policy: &UploadPolicy,
+        sha256: &str,
+        on_progress: Callback<(u64, u64)>,
+        signal: &AbortSignal,
+    ) -> Result<UploadResponse, ApiError> {
         let form_data = FormData::new().map_err(|_| ApiError::FormDataCreation)?;
 
         form_data
             .append_with_blob("file", &file)
             .map_err(|_| ApiError::FormDataAppend)?;
+        form_data
+            .append_with_str("expires_in", &policy.expires_in.to_string())
+            .map_err(|_| ApiError::FormDataAppend)?;
+        if let Some(max_downloads) = policy.max_downloads {
+            form_data
+                .append_with_str("max_downloads", &max_downloads.to_string())
+                .map_err(|_| ApiError::FormDataAppend)?;
+        }
+        form_data
+            .append_with_str("sha256", sha256)
+            .map_err(|_| ApiError::FormDataAppend)?;
 
-        let url = format!("{}/upload", API_URL);
-        
-        let response = Request::post(&url)
-            .body(form_data)
+        let url = format!("{}/upload", self.base_url);
+        on_progress.emit((0, file.size() as u64));
+
+        let (status, body) =
+            send_xhr("POST", &url, XhrBody::Form(&form_data), &on_progress, signal).await?;
+
+        if (200..300).contains(&status) {
+            serde_json::from_str::<UploadResponse>(&body)
+                .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))
+        } else {
+            Err(ApiError::ServerError(status))
+        }
+    }
+
+    // Exclui o arquivo no servidor; 404 conta como sucesso (já não existe)
+    pub async fn delete_file(&self, file_id: &str, deletion_token: &str) -> Result<(), ApiError> {
+        let url = format!("{}/files/{}", self.base_url, file_id);
+
+        let response = Request::delete(&url)
+            .header("X-Deletion-Token", deletion_token)
+            .send()
+            .await
+            .map_err(|e| ApiError::NetworkError(format!("{:?}", e)))?;
+
+        if response.ok() || response.status() == 404 {
+            Ok(())
+        } else {
+            Err(ApiError::from_status(response.status()))
+        }
+    }
+
+    // Nova validade contada a partir de agora
+    pub async fn extend_expiry(
+        &self,
+        file_id: &str,
+        owner_token: &str,
+        expires_in: u64,
+    ) -> Result<FileUpdated, ApiError> {
+        let body = serde_json::json!({ "expires_in": expires_in });
+        self.update_file(file_id, owner_token, &body).await
+    }
+
+    // `None` remove o limite de downloads
+    pub async fn set_download_limit(
+        &self,
+        file_id: &str,
+        owner_token: &str,
+        max_downloads: Option<u32>,
+    ) -> Result<FileUpdated, ApiError> {
+        let body = serde_json::json!({ "max_downloads": max_downloads });
+        self.update_file(file_id, owner_token, &body).await
+    }
+
+    async fn update_file(
+        &self,
+        file_id: &str,
+        owner_token: &str,
+        body: &serde_json::Value,
+    ) -> Result<FileUpdated, ApiError> {
+        let url = format!("{}/files/{}", self.base_url, file_id);
+
+        let response = Request::patch(&url)
+            .header("X-Deletion-Token", owner_token)
+            .json(body)
             .map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?
             .send()
             .await
@@ -85,11 +447,444 @@ impl ApiClient {
 
         if response.ok() {
             response
-                .json::<UploadResponse>()
+                .json::<FileUpdated>()
                 .await
                 .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))
         } else {
-            Err(ApiError::ServerError(response.status()))
+            Err(ApiError::from_status(response.status()))
         }
     }
-}
\ No newline at end of file
+
+    pub async fn get_file_info(&self, file_id: &str) -> Result<FileInfo, ApiError> {
+        let url = format!("{}/files/{}", self.base_url, file_id);
+
+        let response = Request::get(&url)
+            .send()
+            .await
+            .map_err(|e| ApiError::NetworkError(format!("{:?}", e)))?;
+
+        if response.ok() {
+            response
+                .json::<FileInfo>()
+                .await
+                .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))
+        } else {
+            Err(ApiError::from_status(response.status()))
+        }
+    }
+
+    // Recursos do backend; se a rota não existir, nada de upload em partes
+    pub async fn fetch_capabilities(&self) -> ServerCapabilities {
+        let url = format!("{}/capabilities", self.base_url);
+
+        match Request::get(&url).send().await {
+            Ok(response) if response.ok() => response.json().await.unwrap_or_default(),
+            _ => ServerCapabilities::default(),
+        }
+    }
+
+    // Upload em partes com retentativa por parte. Com `resumable`, o progresso
+    // da sessão fica salvo no StorageService, então selecionar o mesmo arquivo
+    // depois de uma falha (ou de recarregar a página) retoma de onde parou.
+    // Arquivos criptografados não são retomáveis: cada tentativa cifra com
+    // chave e nonce novos, então as partes já enviadas não servem mais
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upload_file_chunked(
+        &self,
+        file: File,
+        chunk_size: u64,
+        policy: &UploadPolicy,
+        sha256: &str,
+        resumable: bool,
+        on_progress: Callback<(u64, u64)>,
+        signal: &AbortSignal,
+    ) -> Result<UploadResponse, ApiError> {
+        let size = file.size() as u64;
+        let total_chunks = size.div_ceil(chunk_size).max(1) as u32;
+        // A política entra na chave: a sessão no backend foi criada com ela
+        let fingerprint = format!(
+            "{}:{}:{}:{}:{:?}",
+            file.name(),
+            size,
+            file.last_modified(),
+            policy.expires_in,
+            policy.max_downloads
+        );
+
+        let resumed = match resumable {
+            true => self.resume_upload_session(&fingerprint, chunk_size, signal).await?,
+            false => None,
+        };
+        let mut session = match resumed {
+            Some(session) => session,
+            None => {
+                self.create_upload_session(&file, fingerprint, chunk_size, policy, sha256, signal)
+                    .await?
+            }
+        };
+        let save_session = |session: &UploadSession| {
+            if resumable {
+                StorageService::save_upload_session(session);
+            }
+        };
+        save_session(&session);
+
+        // Tamanho real de cada parte (a última costuma ser menor)
+        let chunk_len = |index: u32| size.saturating_sub(index as u64 * chunk_size).min(chunk_size);
+        let mut sent_before: u64 = session.completed_chunks.iter().map(|&i| chunk_len(i)).sum();
+        // Primeiro evento: o que já estava no servidor, fora da taxa de envio
+        on_progress.emit((sent_before, size));
+
+        for index in 0..total_chunks {
+            if session.completed_chunks.contains(&index) {
+                continue;
+            }
+
+            let start = index as u64 * chunk_size;
+            let end = (start + chunk_size).min(size);
+            let chunk = file
+                .slice_with_f64_and_f64(start as f64, end as f64)
+                .map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?;
+
+            // Bytes já enviados nas outras partes + progresso desta parte
+            let chunk_progress = {
+                let on_progress = on_progress.clone();
+                Callback::from(move |(loaded, _): (u64, u64)| {
+                    on_progress.emit(((sent_before + loaded).min(size), size));
+                })
+            };
+
+            let url = format!("{}/uploads/{}/chunks/{}", self.base_url, session.upload_id, index);
+            send_chunk(&url, &chunk, &chunk_progress, signal).await?;
+
+            session.completed_chunks.push(index);
+            sent_before += chunk_len(index);
+            save_session(&session);
+        }
+
+        let url = format!("{}/uploads/{}/complete", self.base_url, session.upload_id);
+        let response = Request::post(&url)
+            .abort_signal(Some(signal))
+            .send()
+            .await
+            .map_err(|e| request_error(e, signal))?;
+
+        if !response.ok() {
+            return Err(ApiError::ServerError(response.status()));
+        }
+
+        StorageService::remove_upload_session(&session.fingerprint);
+        on_progress.emit((size, size));
+
+        response
+            .json::<UploadResponse>()
+            .await
+            .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))
+    }
+
+    async fn create_upload_session(
+        &self,
+        file: &File,
+        fingerprint: String,
+        chunk_size: u64,
+        policy: &UploadPolicy,
+        sha256: &str,
+        signal: &AbortSignal,
+    ) -> Result<UploadSession, ApiError> {
+        let url = format!("{}/uploads", self.base_url);
+        let init = ChunkedUploadInit {
+            filename: &file.name(),
+            size: file.size() as u64,
+            chunk_size,
+            expires_in: policy.expires_in,
+            max_downloads: policy.max_downloads,
+            sha256,
+        };
+
+        let response = Request::post(&url)
+            .abort_signal(Some(signal))
+            .json(&init)
+            .map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?
+            .send()
+            .await
+            .map_err(|e| request_error(e, signal))?;
+
+        if !response.ok() {
+            return Err(ApiError::ServerError(response.status()));
+        }
+
+        let created = response
+            .json::<ChunkedUploadCreated>()
+            .await
+            .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))?;
+
+        Ok(UploadSession {
+            fingerprint,
+            upload_id: created.upload_id,
+            chunk_size,
+            completed_chunks: Vec::new(),
+            created_at: js_sys::Date::now(),
+        })
+    }
+
+    // Consulta o backend sobre uma sessão salva; sessões expiradas são descartadas
+    async fn resume_upload_session(
+        &self,
+        fingerprint: &str,
+        chunk_size: u64,
+        signal: &AbortSignal,
+    ) -> Result<Option<UploadSession>, ApiError> {
+        let Some(mut session) = StorageService::load_upload_session(fingerprint) else {
+            return Ok(None);
+        };
+
+        if session.chunk_size != chunk_size {
+            StorageService::remove_upload_session(fingerprint);
+            return Ok(None);
+        }
+
+        let url = format!("{}/uploads/{}", self.base_url, session.upload_id);
+        let response = Request::get(&url)
+            .abort_signal(Some(signal))
+            .send()
+            .await
+            .map_err(|e| request_error(e, signal))?;
+
+        if !response.ok() {
+            StorageService::remove_upload_session(fingerprint);
+            return Ok(None);
+        }
+
+        let state = response
+            .json::<ChunkedUploadState>()
+            .await
+            .map_err(|e| ApiError::JsonParse(format!("{:?}", e)))?;
+
+        // O backend é a referência sobre quais partes chegaram
+        session.completed_chunks = state.received_chunks;
+        Ok(Some(session))
+    }
+}
+
+// Consome o ReadableStream da resposta guardando os pedaços em `parts` (no
+// lado JS, sem passar pela memória do WASM). O progresso é emitido no máximo
+// a cada DOWNLOAD_PROGRESS_INTERVAL_MS. `on_checkpoint` recebe só os pedaços
+// novos desde a chamada anterior e o offset onde eles começam: a cada
+// DOWNLOAD_CHECKPOINT_BYTES e, se a leitura falhar, com o que sobrou
+async fn read_stream(
+    response: &gloo_net::http::Response,
+    offset: u64,
+    total: u64,
+    on_progress: &Callback<(u64, u64)>,
+    parts: &js_sys::Array,
+    on_checkpoint: impl AsyncFn(&js_sys::Array, u64),
+) -> Result<(), ApiError> {
+    let reader = body_reader(response)?;
+    // Primeiro evento: o que veio do parcial salvo, fora da taxa de download
+    on_progress.emit((offset, total));
+
+    let mut loaded = offset;
+    let mut reported_at = 0.0;
+    let mut segment = js_sys::Array::new();
+    let mut segment_start = offset;
+    let result = loop {
+        let chunk = match read_chunk(&reader).await {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break Ok(()),
+            Err(e) => break Err(e),
+        };
+        loaded += chunk.length() as u64;
+        parts.push(&chunk);
+        segment.push(&chunk);
+
+        if loaded - segment_start >= DOWNLOAD_CHECKPOINT_BYTES {
+            on_checkpoint(&segment, segment_start).await;
+            segment = js_sys::Array::new();
+            segment_start = loaded;
+        }
+
+        let now = js_sys::Date::now();
+        if now - reported_at >= DOWNLOAD_PROGRESS_INTERVAL_MS {
+            reported_at = now;
+            on_progress.emit((loaded, total));
+        }
+    };
+
+    // Guarda o resto: numa falha, a próxima tentativa continua daqui; num
+    // sucesso, o arquivo inteiro fica salvo até quem chamou terminar
+    if loaded > segment_start {
+        on_checkpoint(&segment, segment_start).await;
+    }
+    result?;
+
+    on_progress.emit((loaded, total.max(loaded)));
+    Ok(())
+}
+
+fn body_reader(response: &gloo_net::http::Response) -> Result<ReadableStreamDefaultReader, ApiError> {
+    let body = response
+        .body()
+        .ok_or_else(|| ApiError::BrowserApi("resposta sem corpo".to_string()))?;
+    Ok(body.get_reader().unchecked_into())
+}
+
+// Próximo pedaço do corpo; `None` quando a resposta terminou
+async fn read_chunk(reader: &ReadableStreamDefaultReader) -> Result<Option<js_sys::Uint8Array>, ApiError> {
+    // A leitura é rejeitada quando o download é cancelado pelo AbortSignal
+    let result = JsFuture::from(reader.read()).await.map_err(|e| {
+        match e.dyn_ref::<web_sys::DomException>() {
+            Some(e) if e.name() == "AbortError" => ApiError::Aborted,
+            _ => ApiError::NetworkError(format!("{:?}", e)),
+        }
+    })?;
+
+    let done = js_sys::Reflect::get(&result, &"done".into())
+        .map(|done| done.is_truthy())
+        .unwrap_or(true);
+    if done {
+        return Ok(None);
+    }
+
+    js_sys::Reflect::get(&result, &"value".into())
+        .map(|value| Some(value.unchecked_into()))
+        .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))
+}
+
+// Content-Range de uma resposta 206: (início do trecho, tamanho total)
+fn content_range(response: &gloo_net::http::Response) -> (Option<u64>, Option<u64>) {
+    let Some(range) = response.headers().get("content-range") else {
+        return (None, None);
+    };
+    // "bytes 1000-1999/5000"; o total pode vir como "*"
+    let Some((span, total)) = range.trim_start_matches("bytes ").split_once('/') else {
+        return (None, None);
+    };
+    let start = span.split_once('-').and_then(|(start, _)| start.parse().ok());
+    (start, total.parse().ok())
+}
+
+// Descriptografa quando o link trouxe a chave no #fragmento ou o arquivo tem
+// senha. O AES-GCM precisa do conteúdo inteiro, então só aqui os bytes passam
+// pela memória do WASM
+async fn decrypt_download(
+    blob: Blob,
+    file: &StoredFile,
+    password: Option<&str>,
+) -> Result<Blob, ApiError> {
+    let bytes = if file.password_protected {
+        let password = password.ok_or(ApiError::WrongPassword)?;
+        CryptoService::decrypt_with_password(&read_blob(&blob).await?, password)?
+    } else if let Some(key) = &file.encryption_key {
+        let key = CryptoService::decode_key(key)?;
+        CryptoService::decrypt(&read_blob(&blob).await?, &key)?
+    } else {
+        return Ok(blob);
+    };
+
+    let array = js_sys::Uint8Array::from(&bytes[..]);
+    drop(bytes);
+    Blob::new_with_u8_array_sequence(&js_sys::Array::of1(&array))
+        .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))
+}
+
+// Envia uma parte, tentando novamente com espera exponencial em falhas de
+// rede e erros 5xx. Cancelamento e erros 4xx não são repetidos.
+async fn send_chunk(
+    url: &str,
+    chunk: &Blob,
+    on_progress: &Callback<(u64, u64)>,
+    signal: &AbortSignal,
+) -> Result<(), ApiError> {
+    let mut attempt = 0;
+
+    loop {
+        let error = match send_xhr("PUT", url, XhrBody::Blob(chunk), on_progress, signal).await {
+            Ok((status, _)) if (200..300).contains(&status) => return Ok(()),
+            Ok((status, _)) if status < 500 => return Err(ApiError::ServerError(status)),
+            Ok((status, _)) => ApiError::ServerError(status),
+            Err(ApiError::Aborted) => return Err(ApiError::Aborted),
+            Err(e) => e,
+        };
+
+        attempt += 1;
+        if attempt > MAX_CHUNK_RETRIES {
+            return Err(error);
+        }
+        TimeoutFuture::new(500 * 2u32.pow(attempt - 1)).await;
+    }
+}
+
+fn request_error(error: gloo_net::Error, signal: &AbortSignal) -> ApiError {
+    if signal.aborted() {
+        ApiError::Aborted
+    } else {
+        ApiError::NetworkError(format!("{:?}", error))
+    }
+}
+
+enum XhrBody<'a> {
+    Form(&'a FormData),
+    Blob(&'a Blob),
+}
+
+// gloo_net não expõe eventos de progresso, então o envio usa XHR diretamente.
+// Retorna o status HTTP e o corpo da resposta.
+async fn send_xhr(
+    method: &str,
+    url: &str,
+    body: XhrBody<'_>,
+    on_progress: &Callback<(u64, u64)>,
+    signal: &AbortSignal,
+) -> Result<(u16, String), ApiError> {
+    if signal.aborted() {
+        return Err(ApiError::Aborted);
+    }
+
+    let xhr = XmlHttpRequest::new().map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?;
+    xhr.open(method, url)
+        .map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?;
+
+    let progress = {
+        let on_progress = on_progress.clone();
+        Closure::<dyn FnMut(ProgressEvent)>::new(move |e: ProgressEvent| {
+            if e.length_computable() {
+                on_progress.emit((e.loaded() as u64, e.total() as u64));
+            }
+        })
+    };
+    xhr.upload()
+        .map_err(|e| ApiError::RequestBuild(format!("{:?}", e)))?
+        .set_onprogress(Some(progress.as_ref().unchecked_ref()));
+
+    let abort = {
+        let xhr = xhr.clone();
+        Closure::<dyn FnMut()>::new(move || {
+            let _ = xhr.abort();
+        })
+    };
+    signal.set_onabort(Some(abort.as_ref().unchecked_ref()));
+
+    let finished = Promise::new(&mut |resolve, reject| {
+        xhr.set_onload(Some(&resolve));
+        xhr.set_onerror(Some(&reject));
+        xhr.set_onabort(Some(&reject));
+    });
+
+    match body {
+        XhrBody::Form(form) => xhr.send_with_opt_form_data(Some(form)),
+        XhrBody::Blob(blob) => xhr.send_with_opt_blob(Some(blob)),
+    }
+    .map_err(|e| ApiError::NetworkError(format!("{:?}", e)))?;
+
+    let result = JsFuture::from(finished).await;
+    signal.set_onabort(None);
+
+    if signal.aborted() {
+        return Err(ApiError::Aborted);
+    }
+    result.map_err(|_| ApiError::NetworkError("falha na conexão".to_string()))?;
+
+    let status = xhr.status().unwrap_or(0);
+    let text = xhr.response_text().ok().flatten().unwrap_or_default();
+    Ok((status, text))
+}
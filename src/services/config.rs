@@ -0,0 +1,38 @@
+use gloo::console;
+use gloo_net::http::Request;
+use web_sys::RequestCache;
+use crate::models::config::AppConfig;
+use crate::utils::constants::CONFIG_PATH;
+
+pub struct ConfigService;
+
+impl ConfigService {
+    // Carrega e valida /config.json; qualquer falha cai no padrão de build
+    pub async fn load() -> AppConfig {
+        match Self::fetch().await {
+            Ok(config) => config,
+            Err(e) => {
+                console::warn!(format!("Usando configuração padrão: {}", e));
+                AppConfig::default()
+            }
+        }
+    }
+
+    async fn fetch() -> Result<AppConfig, String> {
+        let response = Request::get(CONFIG_PATH)
+            .cache(RequestCache::NoCache)
+            .send()
+            .await
+            .map_err(|e| format!("{:?}", e))?;
+
+        if !response.ok() {
+            return Err(format!("{} retornou {}", CONFIG_PATH, response.status()));
+        }
+
+        response
+            .json::<AppConfig>()
+            .await
+            .map_err(|e| format!("{} inválido: {:?}", CONFIG_PATH, e))?
+            .validate()
+    }
+}
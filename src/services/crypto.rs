@@ -0,0 +1,472 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Blob, File};
+use crate::models::error::ApiError;
+use crate::models::settings::TokenLock;
+
+// Formato do arquivo criptografado: MAGIC (4) + nonce (12) + ciphertext com tag (16)
+const MAGIC: &[u8; 4] = b"QSE1";
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+pub const KEY_LEN: usize = 32;
+
+// Com senha: MAGIC (4) + m_cost, t_cost, p_cost (u32 LE cada) + salt (16)
+// + verificador (16) + nonce (12) + ciphertext com tag (16)
+const PASSWORD_MAGIC: &[u8; 4] = b"QSP1";
+const SALT_LEN: usize = 16;
+const VERIFIER_LEN: usize = 16;
+pub const PASSWORD_HEADER_LEN: usize = PASSWORD_MAGIC.len() + 12 + SALT_LEN + VERIFIER_LEN;
+
+// Argon2id com os parâmetros recomendados pela OWASP (19 MiB, 2 passadas)
+const ARGON2_M_COST: u32 = 19 * 1024;
+const ARGON2_T_COST: u32 = 2;
+const ARGON2_P_COST: u32 = 1;
+// Limites ao ler o cabeçalho, para um arquivo malicioso não travar a aba
+const ARGON2_MAX_M_COST: u32 = 256 * 1024;
+const ARGON2_MAX_T_COST: u32 = 10;
+
+pub struct CryptoService;
+
+impl CryptoService {
+    // Chave AES-256 aleatória gerada pelo crypto.getRandomValues do navegador
+    pub fn generate_key() -> Result<[u8; KEY_LEN], ApiError> {
+        let mut key = [0u8; KEY_LEN];
+        random_bytes(&mut key)?;
+        Ok(key)
+    }
+
+    // Lê e criptografa o arquivo inteiro; devolve o novo arquivo e a chave codificada
+    pub async fn encrypt_file(file: &File) -> Result<(File, String), ApiError> {
+        let key = Self::generate_key()?;
+        let plaintext = read_blob(file).await?;
+        let payload = Self::encrypt(&plaintext, &key)?;
+        Ok((bytes_to_file(&payload, &file.name())?, Self::encode_key(&key)))
+    }
+
+    // Mesmo formato, mas a chave é derivada de uma senha digitada pelo usuário
+    pub async fn encrypt_file_with_password(file: &File, password: &str) -> Result<File, ApiError> {
+        let plaintext = read_blob(file).await?;
+        let payload = Self::encrypt_with_password(&plaintext, password)?;
+        bytes_to_file(&payload, &file.name())
+    }
+
+    pub fn encrypt(plaintext: &[u8], key: &[u8; KEY_LEN]) -> Result<Vec<u8>, ApiError> {
+        let mut payload = MAGIC.to_vec();
+        seal(plaintext, key, &mut payload)?;
+        Ok(payload)
+    }
+
+    pub fn decrypt(payload: &[u8], key: &[u8; KEY_LEN]) -> Result<Vec<u8>, ApiError> {
+        // Cabeçalho ausente: não é um arquivo criptografado com chave
+        if !payload.starts_with(MAGIC) {
+            return Err(ApiError::CorruptedCiphertext);
+        }
+        open(&payload[MAGIC.len()..], key)
+    }
+
+    pub fn encrypt_with_password(plaintext: &[u8], password: &str) -> Result<Vec<u8>, ApiError> {
+        let mut salt = [0u8; SALT_LEN];
+        random_bytes(&mut salt)?;
+        let mut nonce = [0u8; NONCE_LEN];
+        random_bytes(&mut nonce)?;
+
+        let costs = (ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST);
+        seal_with_password(plaintext, password, &salt, &nonce, costs)
+    }
+
+    pub fn decrypt_with_password(payload: &[u8], password: &str) -> Result<Vec<u8>, ApiError> {
+        let key = Self::check_password(payload, password)?;
+        open(&payload[PASSWORD_HEADER_LEN..], &key)
+    }
+
+    // Confere a senha só com o cabeçalho (os primeiros PASSWORD_HEADER_LEN
+    // bytes), antes de baixar o resto; devolve a chave derivada
+    pub fn check_password(payload: &[u8], password: &str) -> Result<[u8; KEY_LEN], ApiError> {
+        if payload.len() < PASSWORD_HEADER_LEN || !payload.starts_with(PASSWORD_MAGIC) {
+            return Err(ApiError::CorruptedCiphertext);
+        }
+
+        let header = &payload[..PASSWORD_HEADER_LEN];
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes(header[offset..offset + 4].try_into().expect("4 bytes"))
+        };
+        let (m_cost, t_cost, p_cost) = (read_u32(4), read_u32(8), read_u32(12));
+        if m_cost > ARGON2_MAX_M_COST || t_cost > ARGON2_MAX_T_COST || p_cost == 0 {
+            return Err(ApiError::CorruptedCiphertext);
+        }
+
+        let salt = &header[16..16 + SALT_LEN];
+        let expected = &header[16 + SALT_LEN..];
+        let (key, verifier) = derive_key(password, salt, m_cost, t_cost, p_cost)?;
+
+        // O verificador separa "senha errada" de "arquivo adulterado"
+        if verifier.as_slice() != expected {
+            return Err(ApiError::WrongPassword);
+        }
+        Ok(key)
+    }
+
+    // Chave local para guardar segredos no navegador (tokens de dono),
+    // derivada de uma frase-senha que nunca é salva
+    pub fn create_token_lock(passphrase: &str) -> Result<([u8; KEY_LEN], TokenLock), ApiError> {
+        let mut salt = [0u8; SALT_LEN];
+        random_bytes(&mut salt)?;
+
+        let (key, verifier) = derive_key(passphrase, &salt, ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST)?;
+        let lock = TokenLock {
+            salt: URL_SAFE_NO_PAD.encode(salt),
+            verifier: URL_SAFE_NO_PAD.encode(verifier),
+        };
+        Ok((key, lock))
+    }
+
+    pub fn unlock_token_lock(passphrase: &str, lock: &TokenLock) -> Result<[u8; KEY_LEN], ApiError> {
+        let salt = URL_SAFE_NO_PAD
+            .decode(&lock.salt)
+            .map_err(|_| ApiError::CorruptedCiphertext)?;
+        let (key, verifier) = derive_key(passphrase, &salt, ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST)?;
+
+        if URL_SAFE_NO_PAD.encode(verifier) != lock.verifier {
+            return Err(ApiError::WrongPassword);
+        }
+        Ok(key)
+    }
+
+    // Texto curto cifrado e codificado em base64url (nonce + ciphertext)
+    pub fn seal_string(text: &str, key: &[u8; KEY_LEN]) -> Result<String, ApiError> {
+        let mut sealed = Vec::with_capacity(NONCE_LEN + text.len() + TAG_LEN);
+        seal(text.as_bytes(), key, &mut sealed)?;
+        Ok(URL_SAFE_NO_PAD.encode(sealed))
+    }
+
+    pub fn open_string(sealed: &str, key: &[u8; KEY_LEN]) -> Result<String, ApiError> {
+        let sealed = URL_SAFE_NO_PAD
+            .decode(sealed)
+            .map_err(|_| ApiError::CorruptedCiphertext)?;
+        String::from_utf8(open(&sealed, key)?).map_err(|_| ApiError::CorruptedCiphertext)
+    }
+
+    // A chave vai no #fragmento do link, que o navegador nunca envia ao servidor
+    pub fn encode_key(key: &[u8; KEY_LEN]) -> String {
+        URL_SAFE_NO_PAD.encode(key)
+    }
+
+    pub fn decode_key(encoded: &str) -> Result<[u8; KEY_LEN], ApiError> {
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ApiError::InvalidKey)
+    }
+}
+
+// nonce (12) + ciphertext com tag, anexados ao fim de `out`
+fn seal(plaintext: &[u8], key: &[u8; KEY_LEN], out: &mut Vec<u8>) -> Result<(), ApiError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    random_bytes(&mut nonce)?;
+    seal_with_nonce(plaintext, key, &nonce, out)
+}
+
+fn seal_with_nonce(
+    plaintext: &[u8],
+    key: &[u8; KEY_LEN],
+    nonce: &[u8; NONCE_LEN],
+    out: &mut Vec<u8>,
+) -> Result<(), ApiError> {
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(nonce), plaintext)
+        .map_err(|_| ApiError::Encryption("falha ao criptografar".to_string()))?;
+
+    out.extend_from_slice(nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(())
+}
+
+// Cabeçalho com os custos do Argon2, o salt e o verificador, seguido do conteúdo selado
+fn seal_with_password(
+    plaintext: &[u8],
+    password: &str,
+    salt: &[u8; SALT_LEN],
+    nonce: &[u8; NONCE_LEN],
+    (m_cost, t_cost, p_cost): (u32, u32, u32),
+) -> Result<Vec<u8>, ApiError> {
+    let (key, verifier) = derive_key(password, salt, m_cost, t_cost, p_cost)?;
+
+    let mut payload = Vec::with_capacity(PASSWORD_HEADER_LEN + NONCE_LEN + plaintext.len() + TAG_LEN);
+    payload.extend_from_slice(PASSWORD_MAGIC);
+    payload.extend_from_slice(&m_cost.to_le_bytes());
+    payload.extend_from_slice(&t_cost.to_le_bytes());
+    payload.extend_from_slice(&p_cost.to_le_bytes());
+    payload.extend_from_slice(salt);
+    payload.extend_from_slice(&verifier);
+    seal_with_nonce(plaintext, &key, nonce, &mut payload)?;
+    Ok(payload)
+}
+
+fn open(sealed: &[u8], key: &[u8; KEY_LEN]) -> Result<Vec<u8>, ApiError> {
+    // Menor que nonce + tag: dado truncado
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        return Err(ApiError::CorruptedCiphertext);
+    }
+
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+
+    // A tag GCM não confere: conteúdo alterado, cortado ou chave errada
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| ApiError::DecryptionFailed)
+}
+
+// Deriva a chave AES e o verificador de senha com Argon2id
+fn derive_key(
+    password: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<([u8; KEY_LEN], [u8; VERIFIER_LEN]), ApiError> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(KEY_LEN + VERIFIER_LEN))
+        .map_err(|_| ApiError::CorruptedCiphertext)?;
+
+    let mut output = [0u8; KEY_LEN + VERIFIER_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut output)
+        .map_err(|e| ApiError::Encryption(e.to_string()))?;
+
+    let (key, verifier) = output.split_at(KEY_LEN);
+    Ok((
+        key.try_into().expect("KEY_LEN bytes"),
+        verifier.try_into().expect("VERIFIER_LEN bytes"),
+    ))
+}
+
+fn random_bytes(buf: &mut [u8]) -> Result<(), ApiError> {
+    web_sys::window()
+        .ok_or_else(|| ApiError::Encryption("window indisponível".to_string()))?
+        .crypto()
+        .and_then(|crypto| crypto.get_random_values_with_u8_array(buf))
+        .map(|_| ())
+        .map_err(|e| ApiError::Encryption(format!("{:?}", e)))
+}
+
+pub async fn read_blob(blob: &Blob) -> Result<Vec<u8>, ApiError> {
+    let buffer = JsFuture::from(blob.array_buffer())
+        .await
+        .map_err(|e| ApiError::FileRead(format!("{:?}", e)))?;
+    Ok(Uint8Array::new(&buffer).to_vec())
+}
+
+fn bytes_to_file(bytes: &[u8], name: &str) -> Result<File, ApiError> {
+    let array = Uint8Array::from(bytes);
+    File::new_with_u8_array_sequence(&Array::of1(&array), name)
+        .map_err(|e| ApiError::FileRead(format!("{:?}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Chave e nonce fixos: os testes não dependem do crypto.getRandomValues
+    const KEY: [u8; KEY_LEN] = [7; KEY_LEN];
+    const NONCE: [u8; NONCE_LEN] = [9; NONCE_LEN];
+    const PLAINTEXT: &[u8] = b"conteudo do arquivo";
+
+    fn encrypted() -> Vec<u8> {
+        let mut payload = MAGIC.to_vec();
+        seal_with_nonce(PLAINTEXT, &KEY, &NONCE, &mut payload).unwrap();
+        payload
+    }
+
+    #[test]
+    fn decrypts_what_was_sealed() {
+        let payload = encrypted();
+        assert_eq!(payload.len(), MAGIC.len() + NONCE_LEN + PLAINTEXT.len() + TAG_LEN);
+        assert_eq!(CryptoService::decrypt(&payload, &KEY).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails() {
+        let mut payload = encrypted();
+        payload[MAGIC.len() + NONCE_LEN] ^= 1;
+        assert_eq!(CryptoService::decrypt(&payload, &KEY), Err(ApiError::DecryptionFailed));
+    }
+
+    #[test]
+    fn tampered_tag_fails() {
+        let mut payload = encrypted();
+        *payload.last_mut().unwrap() ^= 1;
+        assert_eq!(CryptoService::decrypt(&payload, &KEY), Err(ApiError::DecryptionFailed));
+    }
+
+    #[test]
+    fn tampered_nonce_fails() {
+        let mut payload = encrypted();
+        payload[MAGIC.len()] ^= 1;
+        assert_eq!(CryptoService::decrypt(&payload, &KEY), Err(ApiError::DecryptionFailed));
+    }
+
+    #[test]
+    fn truncated_input_is_corrupted() {
+        let payload = encrypted();
+        for len in [0, MAGIC.len(), MAGIC.len() + NONCE_LEN, MAGIC.len() + NONCE_LEN + TAG_LEN - 1] {
+            assert_eq!(
+                CryptoService::decrypt(&payload[..len], &KEY),
+                Err(ApiError::CorruptedCiphertext),
+                "{} bytes",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn cut_tail_fails_authentication() {
+        let payload = encrypted();
+        assert_eq!(
+            CryptoService::decrypt(&payload[..payload.len() - 1], &KEY),
+            Err(ApiError::DecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn bad_magic_is_corrupted() {
+        let mut payload = encrypted();
+        payload[..MAGIC.len()].copy_from_slice(b"QSE0");
+        assert_eq!(CryptoService::decrypt(&payload, &KEY), Err(ApiError::CorruptedCiphertext));
+        // Arquivo com senha não abre pelo caminho da chave
+        payload[..MAGIC.len()].copy_from_slice(PASSWORD_MAGIC);
+        assert_eq!(CryptoService::decrypt(&payload, &KEY), Err(ApiError::CorruptedCiphertext));
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let payload = encrypted();
+        let mut other = KEY;
+        other[0] ^= 1;
+        assert_eq!(CryptoService::decrypt(&payload, &other), Err(ApiError::DecryptionFailed));
+    }
+
+    #[test]
+    fn open_string_rejects_bad_input() {
+        let mut sealed = Vec::new();
+        seal_with_nonce(b"token", &KEY, &NONCE, &mut sealed).unwrap();
+        let encoded = URL_SAFE_NO_PAD.encode(&sealed);
+        assert_eq!(CryptoService::open_string(&encoded, &KEY).unwrap(), "token");
+
+        assert_eq!(CryptoService::open_string("não é base64", &KEY), Err(ApiError::CorruptedCiphertext));
+        assert_eq!(
+            CryptoService::open_string(&URL_SAFE_NO_PAD.encode([0u8; 8]), &KEY),
+            Err(ApiError::CorruptedCiphertext)
+        );
+        sealed[NONCE_LEN] ^= 1;
+        assert_eq!(
+            CryptoService::open_string(&URL_SAFE_NO_PAD.encode(&sealed), &KEY),
+            Err(ApiError::DecryptionFailed)
+        );
+    }
+
+    // Custos baixos para o teste não demorar; o formato guarda os custos no cabeçalho
+    const TEST_COSTS: (u32, u32, u32) = (64, 1, 1);
+    const SALT: [u8; SALT_LEN] = [3; SALT_LEN];
+
+    fn encrypted_with_password(password: &str) -> Vec<u8> {
+        seal_with_password(PLAINTEXT, password, &SALT, &NONCE, TEST_COSTS).unwrap()
+    }
+
+    #[test]
+    fn decrypts_with_the_right_password() {
+        let payload = encrypted_with_password("senha certa");
+        assert_eq!(CryptoService::decrypt_with_password(&payload, "senha certa").unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn wrong_password_is_reported_as_such() {
+        let payload = encrypted_with_password("senha certa");
+        assert_eq!(
+            CryptoService::decrypt_with_password(&payload, "senha errada"),
+            Err(ApiError::WrongPassword)
+        );
+        assert_eq!(CryptoService::decrypt_with_password(&payload, ""), Err(ApiError::WrongPassword));
+    }
+
+    #[test]
+    fn password_is_checked_from_the_header_alone() {
+        let payload = encrypted_with_password("senha certa");
+        let header = &payload[..PASSWORD_HEADER_LEN];
+        assert!(CryptoService::check_password(header, "senha certa").is_ok());
+        assert_eq!(CryptoService::check_password(header, "senha errada"), Err(ApiError::WrongPassword));
+    }
+
+    #[test]
+    fn tampered_password_payload_is_not_a_wrong_password() {
+        let mut payload = encrypted_with_password("senha certa");
+        *payload.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            CryptoService::decrypt_with_password(&payload, "senha certa"),
+            Err(ApiError::DecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn password_header_is_validated() {
+        let payload = encrypted_with_password("senha certa");
+        assert_eq!(
+            CryptoService::decrypt_with_password(&payload[..PASSWORD_HEADER_LEN - 1], "senha certa"),
+            Err(ApiError::CorruptedCiphertext)
+        );
+        // Custos acima do limite não chegam a rodar o Argon2
+        let mut expensive = payload.clone();
+        expensive[4..8].copy_from_slice(&(ARGON2_MAX_M_COST + 1).to_le_bytes());
+        assert_eq!(
+            CryptoService::decrypt_with_password(&expensive, "senha certa"),
+            Err(ApiError::CorruptedCiphertext)
+        );
+        let mut keyed = payload;
+        keyed[..PASSWORD_MAGIC.len()].copy_from_slice(MAGIC);
+        assert_eq!(
+            CryptoService::decrypt_with_password(&keyed, "senha certa"),
+            Err(ApiError::CorruptedCiphertext)
+        );
+    }
+
+    #[test]
+    fn verifier_is_stable_for_a_fixed_salt() {
+        let (m, t, p) = TEST_COSTS;
+        let (key, verifier) = derive_key("senha certa", &SALT, m, t, p).unwrap();
+        let (again_key, again_verifier) = derive_key("senha certa", &SALT, m, t, p).unwrap();
+        assert_eq!((key, verifier), (again_key, again_verifier));
+        // Valor conhecido: mudar parâmetros ou a divisão chave/verificador quebra arquivos antigos
+        assert_eq!(URL_SAFE_NO_PAD.encode(verifier), "h0pMmS1XrxHs4uMQ-iQq1w");
+
+        let (_, other_salt) = derive_key("senha certa", &[4; SALT_LEN], m, t, p).unwrap();
+        let (_, other_password) = derive_key("senha errada", &SALT, m, t, p).unwrap();
+        assert_ne!(verifier, other_salt);
+        assert_ne!(verifier, other_password);
+    }
+
+    #[test]
+    fn token_lock_rejects_wrong_passphrase() {
+        let (m, t, p) = (ARGON2_M_COST, ARGON2_T_COST, ARGON2_P_COST);
+        let (key, verifier) = derive_key("frase certa", &SALT, m, t, p).unwrap();
+        let lock = TokenLock {
+            salt: URL_SAFE_NO_PAD.encode(SALT),
+            verifier: URL_SAFE_NO_PAD.encode(verifier),
+        };
+        assert_eq!(CryptoService::unlock_token_lock("frase certa", &lock), Ok(key));
+        assert_eq!(CryptoService::unlock_token_lock("frase errada", &lock), Err(ApiError::WrongPassword));
+    }
+
+    #[test]
+    fn decode_key_checks_length() {
+        let encoded = CryptoService::encode_key(&KEY);
+        assert_eq!(CryptoService::decode_key(&encoded), Ok(KEY));
+        assert_eq!(CryptoService::decode_key(&encoded[..10]), Err(ApiError::InvalidKey));
+        assert_eq!(CryptoService::decode_key("***"), Err(ApiError::InvalidKey));
+    }
+}
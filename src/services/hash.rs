@@ -0,0 +1,38 @@
+use sha2::{Digest, Sha256};
+use web_sys::Blob;
+use crate::models::error::ApiError;
+use crate::services::crypto::read_blob;
+use crate::utils::constants::HASH_CHUNK_BYTES;
+
+// SHA-256 calculado no WASM, lendo o arquivo aos pedaços para não carregar
+// tudo na memória de uma vez
+pub struct HashService;
+
+impl HashService {
+    // Hash em hexadecimal minúsculo, no mesmo formato do `sha256sum`
+    pub async fn sha256(blob: &Blob) -> Result<String, ApiError> {
+        let size = blob.size();
+        let mut hasher = Sha256::new();
+        let mut start = 0.0;
+
+        while start < size {
+            let end = (start + HASH_CHUNK_BYTES).min(size);
+            let chunk = blob
+                .slice_with_f64_and_f64(start, end)
+                .map_err(|e| ApiError::FileRead(format!("{:?}", e)))?;
+            hasher.update(read_blob(&chunk).await?);
+            start = end;
+        }
+
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect())
+    }
+
+    // Compara ignorando maiúsculas, já que alguns backends devolvem em caixa alta
+    pub fn matches(expected: &str, actual: &str) -> bool {
+        expected.trim().eq_ignore_ascii_case(actual)
+    }
+}
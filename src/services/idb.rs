@@ -0,0 +1,219 @@
+use js_sys::{Array, Promise};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbKeyRange, IdbObjectStore, IdbRequest, IdbTransactionMode};
+use crate::models::error::ApiError;
+use crate::utils::constants::{IDB_NAME, IDB_STORES, IDB_VERSION};
+
+// Acesso mínimo ao IndexedDB, usado para o que não cabe no localStorage
+// (arquivos e blobs). As chaves ficam fora do valor (out-of-line).
+pub struct IdbService;
+
+impl IdbService {
+    // Abre uma conexão nova; quem abre fecha (ver `run`). Se outra aba ainda
+    // estiver com uma versão antiga aberta, falha em vez de esperar para sempre
+    async fn open() -> Result<IdbDatabase, ApiError> {
+        let factory = web_sys::window()
+            .and_then(|w| w.indexed_db().ok().flatten())
+            .ok_or_else(|| ApiError::Storage("IndexedDB indisponível".to_string()))?;
+
+        let request = factory
+            .open_with_u32(IDB_NAME, IDB_VERSION)
+            .map_err(storage_error)?;
+
+        // Cria os object stores que ainda não existem
+        let on_upgrade = {
+            let request = request.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                let Ok(db) = request.result().and_then(|db| db.dyn_into::<IdbDatabase>()) else {
+                    return;
+                };
+                let existing = db.object_store_names();
+                for store in IDB_STORES {
+                    if !existing.contains(store) {
+                        let _ = db.create_object_store(store);
+                    }
+                }
+            })
+        };
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+        let mut on_blocked = None;
+        let done = Promise::new(&mut |resolve, reject| {
+            request.set_onsuccess(Some(&resolve));
+            request.set_onerror(Some(&reject));
+            on_blocked = Some(Closure::<dyn FnMut()>::new(move || {
+                let message = JsValue::from_str("banco bloqueado por outra aba aberta do QuickShare");
+                let _ = reject.call1(&JsValue::NULL, &message);
+            }));
+        });
+        if let Some(on_blocked) = &on_blocked {
+            request.set_onblocked(Some(on_blocked.as_ref().unchecked_ref()));
+        }
+        let result = JsFuture::from(done).await;
+        request.set_onupgradeneeded(None);
+        request.set_onerror(None);
+        request.set_onblocked(None);
+
+        if let Err(e) = result {
+            // A abertura segue pendente; se um dia concluir, a conexão é fechada
+            let pending = request.clone();
+            let close_later = Closure::once_into_js(move || {
+                if let Ok(db) = pending.result().and_then(|db| db.dyn_into::<IdbDatabase>()) {
+                    db.close();
+                }
+            });
+            request.set_onsuccess(Some(close_later.unchecked_ref()));
+            return Err(storage_error(e));
+        }
+        request.set_onsuccess(None);
+
+        request
+            .result()
+            .and_then(|db| db.dyn_into::<IdbDatabase>())
+            .map_err(storage_error)
+    }
+
+    // Uma requisição em uma transação própria, com a conexão fechada ao fim
+    async fn run(
+        store: &str,
+        mode: IdbTransactionMode,
+        op: impl FnOnce(&IdbObjectStore) -> Result<IdbRequest, JsValue>,
+    ) -> Result<JsValue, ApiError> {
+        let db = Self::open().await?;
+        let _on_version_change = close_on_version_change(&db);
+
+        let request = db
+            .transaction_with_str_and_mode(store, mode)
+            .and_then(|tx| tx.object_store(store))
+            .and_then(|s| op(&s))
+            .map_err(storage_error);
+        let result = match request {
+            Ok(request) => wait(&request).await,
+            Err(e) => Err(e),
+        };
+
+        db.set_onversionchange(None);
+        db.close();
+        result
+    }
+
+    pub async fn get_all(store: &str) -> Result<Vec<JsValue>, ApiError> {
+        let values = Self::run(store, IdbTransactionMode::Readonly, |s| s.get_all()).await?;
+        Ok(values.dyn_into::<Array>().map(|a| a.to_vec()).unwrap_or_default())
+    }
+
+    // Pares (chave, valor) lidos na mesma transação, em ordem de chave
+    pub async fn get_all_entries(store: &str) -> Result<Vec<(String, JsValue)>, ApiError> {
+        let db = Self::open().await?;
+        let _on_version_change = close_on_version_change(&db);
+        let requests = db
+            .transaction_with_str(store)
+            .and_then(|tx| tx.object_store(store))
+            .and_then(|s| Ok((s.get_all_keys()?, s.get_all()?)))
+            .map_err(storage_error);
+        let result = match requests {
+            Ok((keys, values)) => match (wait(&keys).await, wait(&values).await) {
+                (Ok(keys), Ok(values)) => Ok((keys, values)),
+                (Err(e), _) | (_, Err(e)) => Err(e),
+            },
+            Err(e) => Err(e),
+        };
+        db.set_onversionchange(None);
+        db.close();
+
+        let (keys, values) = result?;
+        let to_vec = |v: JsValue| v.dyn_into::<Array>().map(|a| a.to_vec()).unwrap_or_default();
+        Ok(to_vec(keys)
+            .into_iter()
+            .zip(to_vec(values))
+            .filter_map(|(key, value)| Some((key.as_string()?, value)))
+            .collect())
+    }
+
+    // Valores cujas chaves começam com `prefix`, em ordem de chave
+    pub async fn get_all_with_prefix(store: &str, prefix: &str) -> Result<Vec<JsValue>, ApiError> {
+        let values = Self::run(store, IdbTransactionMode::Readonly, |s| {
+            s.get_all_with_key(&prefix_range(prefix)?)
+        })
+        .await?;
+        Ok(values.dyn_into::<Array>().map(|a| a.to_vec()).unwrap_or_default())
+    }
+
+    pub async fn get(store: &str, key: &str) -> Result<Option<JsValue>, ApiError> {
+        let value = Self::run(store, IdbTransactionMode::Readonly, |s| s.get(&JsValue::from_str(key))).await?;
+        Ok((!value.is_undefined()).then_some(value))
+    }
+
+    pub async fn put(store: &str, key: &str, value: &JsValue) -> Result<(), ApiError> {
+        Self::run(store, IdbTransactionMode::Readwrite, |s| {
+            s.put_with_key(value, &JsValue::from_str(key))
+        })
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn delete(store: &str, key: &str) -> Result<(), ApiError> {
+        Self::run(store, IdbTransactionMode::Readwrite, |s| s.delete(&JsValue::from_str(key)))
+            .await
+            .map(|_| ())
+    }
+
+    // Várias chaves numa transação só; a última requisição termina depois das outras
+    pub async fn delete_keys(store: &str, keys: &[String]) -> Result<(), ApiError> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        Self::run(store, IdbTransactionMode::Readwrite, |s| {
+            let mut last = None;
+            for key in keys {
+                last = Some(s.delete(&JsValue::from_str(key))?);
+            }
+            Ok(last.expect("ao menos uma chave"))
+        })
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn delete_prefix(store: &str, prefix: &str) -> Result<(), ApiError> {
+        Self::run(store, IdbTransactionMode::Readwrite, |s| s.delete(&prefix_range(prefix)?))
+            .await
+            .map(|_| ())
+    }
+}
+
+// Enquanto a conexão está aberta, uma aba com versão nova do banco a derruba
+// em vez de ficar bloqueada; o handler vale enquanto o retorno existir
+fn close_on_version_change(db: &IdbDatabase) -> Closure<dyn FnMut()> {
+    let on_version_change = {
+        let db = db.clone();
+        Closure::<dyn FnMut()>::new(move || db.close())
+    };
+    db.set_onversionchange(Some(on_version_change.as_ref().unchecked_ref()));
+    on_version_change
+}
+
+// Todas as chaves de texto que começam com `prefix`
+fn prefix_range(prefix: &str) -> Result<JsValue, JsValue> {
+    let upper = format!("{}\u{ffff}", prefix);
+    IdbKeyRange::bound(&prefix.into(), &upper.into()).map(Into::into)
+}
+
+// Espera o onsuccess/onerror de uma requisição do IndexedDB
+async fn wait(request: &IdbRequest) -> Result<JsValue, ApiError> {
+    let done = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    let result = JsFuture::from(done).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+
+    result.map_err(storage_error)?;
+    request.result().map_err(storage_error)
+}
+
+fn storage_error(e: JsValue) -> ApiError {
+    ApiError::Storage(format!("{:?}", e))
+}
@@ -1,2 +1,12 @@
 pub mod api;
+pub mod config;
+pub mod crypto;
+pub mod hash;
+pub mod idb;
+pub mod outbox;
+pub mod partial_download;
+pub mod preview;
+pub mod pwa;
+pub mod qr;
+pub mod share;
 pub mod storage;
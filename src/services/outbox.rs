@@ -0,0 +1,86 @@
+use gloo::console;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::File;
+use crate::models::upload::{OutboxRecord, UploadItem, UploadStatus};
+use crate::services::idb::IdbService;
+use crate::utils::constants::OUTBOX_STORE;
+
+// Caixa de saída: os arquivos da fila ficam no IndexedDB até serem enviados,
+// para sobreviver a recargas da página e a períodos offline
+pub struct OutboxService;
+
+impl OutboxService {
+    pub fn new_key() -> String {
+        format!(
+            "{:x}-{:x}",
+            js_sys::Date::now() as u64,
+            (js_sys::Math::random() * u32::MAX as f64) as u32
+        )
+    }
+
+    pub fn save(item: &UploadItem) {
+        // A senha nunca vai para o disco; envios com senha ficam só na memória
+        if item.options.password.is_some() {
+            return;
+        }
+
+        let record = Object::new();
+        let error = match &item.status {
+            UploadStatus::Failed(e) => JsValue::from_str(e),
+            _ => JsValue::NULL,
+        };
+        let _ = Reflect::set(&record, &"key".into(), &item.key.as_str().into());
+        let _ = Reflect::set(&record, &"file".into(), &item.file);
+        let _ = Reflect::set(&record, &"encrypt".into(), &item.options.encrypt.into());
+        if let Ok(policy) = serde_json::to_string(&item.options.policy) {
+            let _ = Reflect::set(&record, &"policy".into(), &policy.into());
+        }
+        let _ = Reflect::set(&record, &"attempts".into(), &item.attempts.into());
+        let _ = Reflect::set(&record, &"error".into(), &error);
+
+        let key = item.key.clone();
+        spawn_local(async move {
+            if let Err(e) = IdbService::put(OUTBOX_STORE, &key, &record).await {
+                console::warn!(format!("Falha ao salvar na caixa de saída: {}", e));
+            }
+        });
+    }
+
+    pub fn remove(key: &str) {
+        let key = key.to_string();
+        spawn_local(async move {
+            if let Err(e) = IdbService::delete(OUTBOX_STORE, &key).await {
+                console::warn!(format!("Falha ao remover da caixa de saída: {}", e));
+            }
+        });
+    }
+
+    pub async fn load() -> Vec<OutboxRecord> {
+        match IdbService::get_all(OUTBOX_STORE).await {
+            Ok(values) => values.iter().filter_map(parse_record).collect(),
+            Err(e) => {
+                console::warn!(format!("Falha ao ler a caixa de saída: {}", e));
+                Vec::new()
+            }
+        }
+    }
+}
+
+fn parse_record(value: &JsValue) -> Option<OutboxRecord> {
+    let get = |field: &str| Reflect::get(value, &field.into()).ok();
+
+    Some(OutboxRecord {
+        key: get("key")?.as_string()?,
+        file: get("file")?.dyn_into::<File>().ok()?,
+        encrypt: get("encrypt").and_then(|v| v.as_bool()).unwrap_or(false),
+        policy: get("policy")
+            .and_then(|v| v.as_string())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default(),
+        attempts: get("attempts").and_then(|v| v.as_f64()).unwrap_or(0.0) as u32,
+        error: get("error").and_then(|v| v.as_string()),
+    })
+}
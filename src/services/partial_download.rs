@@ -0,0 +1,115 @@
+use gloo::console;
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use web_sys::Blob;
+use crate::models::file::PartialDownload;
+use crate::services::idb::IdbService;
+use crate::utils::constants::PARTIAL_DOWNLOADS_STORE;
+
+// Pedaços já recebidos de downloads interrompidos, por file_id, para retomar
+// com Range em vez de começar do zero. Cada download tem um registro de
+// metadados (chave = file_id) e um registro por trecho recebido
+// (chave = "file_id:offset"), só acrescentados, nunca reescritos
+pub struct PartialDownloadService;
+
+impl PartialDownloadService {
+    pub async fn load(file_id: &str) -> Option<PartialDownload> {
+        let meta = match IdbService::get(PARTIAL_DOWNLOADS_STORE, file_id).await {
+            Ok(value) => value?,
+            Err(e) => {
+                console::warn!(format!("Falha ao ler download parcial: {}", e));
+                return None;
+            }
+        };
+        let segments = IdbService::get_all_with_prefix(PARTIAL_DOWNLOADS_STORE, &segment_prefix(file_id))
+            .await
+            .unwrap_or_default();
+
+        // Junta os trechos em ordem e para no primeiro buraco
+        let parts = Array::new();
+        let mut received = 0u64;
+        for segment in &segments {
+            let offset = Reflect::get(segment, &"offset".into()).ok().and_then(|v| v.as_f64());
+            let blob = Reflect::get(segment, &"blob".into()).ok().and_then(|v| v.dyn_into::<Blob>().ok());
+            match (offset, blob) {
+                (Some(offset), Some(blob)) if offset as u64 == received => {
+                    received += blob.size() as u64;
+                    parts.push(&blob);
+                }
+                _ => break,
+            }
+        }
+
+        let blob = Blob::new_with_blob_sequence(&parts).ok()?;
+        parse_meta(&meta, blob)
+    }
+
+    // Registra um download novo que pode ser retomado; os trechos vêm depois
+    pub async fn start(file_id: &str, validator: &str, total: u64, expires_at: &str) {
+        let record = Object::new();
+        let _ = Reflect::set(&record, &"file_id".into(), &file_id.into());
+        let _ = Reflect::set(&record, &"validator".into(), &validator.into());
+        let _ = Reflect::set(&record, &"total".into(), &(total as f64).into());
+        let _ = Reflect::set(&record, &"expires_at".into(), &expires_at.into());
+
+        if let Err(e) = IdbService::put(PARTIAL_DOWNLOADS_STORE, file_id, &record).await {
+            console::warn!(format!("Falha ao salvar download parcial: {}", e));
+        }
+    }
+
+    // Acrescenta um trecho recebido a partir de `offset`
+    pub async fn append(file_id: &str, offset: u64, blob: &Blob) {
+        let record = Object::new();
+        let _ = Reflect::set(&record, &"offset".into(), &(offset as f64).into());
+        let _ = Reflect::set(&record, &"blob".into(), blob);
+
+        // Offset com zeros à esquerda: a ordem das chaves é a ordem dos bytes
+        let key = format!("{}{:020}", segment_prefix(file_id), offset);
+        if let Err(e) = IdbService::put(PARTIAL_DOWNLOADS_STORE, &key, &record).await {
+            console::warn!(format!("Falha ao salvar trecho do download: {}", e));
+        }
+    }
+
+    pub async fn remove(file_id: &str) {
+        let segments = IdbService::delete_prefix(PARTIAL_DOWNLOADS_STORE, &segment_prefix(file_id)).await;
+        let meta = IdbService::delete(PARTIAL_DOWNLOADS_STORE, file_id).await;
+        if let Err(e) = segments.and(meta) {
+            console::warn!(format!("Falha ao remover download parcial: {}", e));
+        }
+    }
+
+    // Apaga os parciais de arquivos que já expiraram no servidor
+    pub async fn prune_expired() {
+        let Ok(values) = IdbService::get_all(PARTIAL_DOWNLOADS_STORE).await else {
+            return;
+        };
+
+        let now = js_sys::Date::now();
+        for value in &values {
+            let get = |field: &str| Reflect::get(value, &field.into()).ok().and_then(|v| v.as_string());
+            // Só os registros de metadados têm validator
+            if let (Some(file_id), Some(_), Some(expires_at)) = (get("file_id"), get("validator"), get("expires_at"))
+                && js_sys::Date::parse(&expires_at) < now
+            {
+                Self::remove(&file_id).await;
+            }
+        }
+    }
+}
+
+fn segment_prefix(file_id: &str) -> String {
+    format!("{}:", file_id)
+}
+
+fn parse_meta(value: &JsValue, blob: Blob) -> Option<PartialDownload> {
+    let get = |field: &str| Reflect::get(value, &field.into()).ok();
+
+    Some(PartialDownload {
+        file_id: get("file_id")?.as_string()?,
+        blob,
+        validator: get("validator")?.as_string()?,
+        total: get("total").and_then(|v| v.as_f64()).unwrap_or(0.0) as u64,
+        expires_at: get("expires_at").and_then(|v| v.as_string()).unwrap_or_default(),
+    })
+}
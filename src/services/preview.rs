@@ -0,0 +1,99 @@
+use web_sys::{AbortSignal, Blob, BlobPropertyBag, Url};
+use crate::models::error::ApiError;
+use crate::models::file::StoredFile;
+use crate::services::api::ApiClient;
+use crate::services::crypto::read_blob;
+use crate::utils::constants::{PREVIEW_MAX_BYTES, PREVIEW_TEXT_BYTES};
+use crate::utils::mime::{detect, Detected, PreviewKind, SNIFF_LEN};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum PreviewContent {
+    Text { text: String, truncated: bool },
+    Url(String), // blob: com o tipo detectado; revogar ao fechar
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Preview {
+    pub kind: PreviewKind,
+    pub content: PreviewContent,
+}
+
+// Busca o mínimo necessário para mostrar o arquivo, em um único download: o
+// tipo vem dos primeiros bytes e, conforme ele, lê só o começo (texto) ou o
+// arquivo inteiro (mídia e PDF). Criptografados precisam vir inteiros para
+// descriptografar
+pub struct PreviewService;
+
+impl PreviewService {
+    pub async fn load(api: &ApiClient, file: &StoredFile, signal: &AbortSignal) -> Result<Preview, ApiError> {
+        let size = api.get_file_info(&file.file_id).await?.size;
+
+        if file.encryption_key.is_some() {
+            check_size(size)?;
+            let blob = api.fetch_decrypted(file, signal).await?;
+            let detected = sniff(&blob).await?;
+            return build(&blob, detected, blob.size() as u64).await;
+        }
+
+        // Um só GET: o tipo sai dos primeiros bytes e define quanto ler
+        let mut detected = None;
+        let blob = api
+            .fetch_prefix(&file.file_id, PREVIEW_MAX_BYTES, SNIFF_LEN, signal, |sample| {
+                let found = detect(sample).ok_or(ApiError::UnknownFileType)?;
+                detected = Some(found);
+                match found.kind {
+                    PreviewKind::Text => Ok(PREVIEW_TEXT_BYTES),
+                    _ => check_size(size).map(|_| size),
+                }
+            })
+            .await?;
+        let detected = detected.ok_or(ApiError::UnknownFileType)?;
+        build(&blob, detected, size).await
+    }
+
+    pub fn release(preview: &Preview) {
+        if let PreviewContent::Url(url) = &preview.content {
+            let _ = Url::revoke_object_url(url);
+        }
+    }
+}
+
+fn check_size(size: u64) -> Result<(), ApiError> {
+    if size > PREVIEW_MAX_BYTES {
+        return Err(ApiError::PreviewTooLarge(PREVIEW_MAX_BYTES));
+    }
+    Ok(())
+}
+
+async fn sniff(blob: &Blob) -> Result<Detected, ApiError> {
+    let head = blob
+        .slice_with_f64_and_f64(0.0, SNIFF_LEN as f64)
+        .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+    detect(&read_blob(&head).await?).ok_or(ApiError::UnknownFileType)
+}
+
+async fn build(blob: &Blob, detected: Detected, size: u64) -> Result<Preview, ApiError> {
+    let content = match detected.kind {
+        PreviewKind::Text => {
+            let head = blob
+                .slice_with_f64_and_f64(0.0, PREVIEW_TEXT_BYTES as f64)
+                .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+            PreviewContent::Text {
+                text: String::from_utf8_lossy(&read_blob(&head).await?).into_owned(),
+                truncated: size > PREVIEW_TEXT_BYTES,
+            }
+        }
+        // O navegador decide como exibir pelo tipo do Blob, não pelo nome
+        _ => {
+            let options = BlobPropertyBag::new();
+            options.set_type(detected.mime);
+            let typed = Blob::new_with_blob_sequence_and_options(&js_sys::Array::of1(blob), &options)
+                .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+            let url = Url::create_object_url_with_blob(&typed)
+                .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+            PreviewContent::Url(url)
+        }
+    };
+
+    Ok(Preview { kind: detected.kind, content })
+}
@@ -0,0 +1,22 @@
+use gloo::console;
+use wasm_bindgen_futures::JsFuture;
+
+pub struct PwaService;
+
+impl PwaService {
+    // Registra o service worker que deixa o shell do app disponível offline
+    pub async fn register_service_worker() {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let container = window.navigator().service_worker();
+
+        if let Err(e) = JsFuture::from(container.register("/sw.js")).await {
+            console::warn!("Falha ao registrar o service worker:", e);
+        }
+    }
+
+    pub fn is_online() -> bool {
+        web_sys::window().is_none_or(|w| w.navigator().on_line())
+    }
+}
@@ -0,0 +1,65 @@
+use qrcode::render::svg;
+use qrcode::types::Color;
+use qrcode::QrCode;
+use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+use crate::models::error::ApiError;
+
+// Margem branca obrigatória em volta do código, em módulos
+const QUIET_ZONE: usize = 4;
+
+// QR codes gerados no próprio navegador, sem serviço externo
+pub struct QrService;
+
+impl QrService {
+    pub fn svg(text: &str) -> Result<String, ApiError> {
+        let svg = QrCode::new(text)
+            .map_err(|e| ApiError::QrCode(e.to_string()))?
+            .render::<svg::Color>()
+            .min_dimensions(256, 256)
+            .build();
+
+        // A declaração XML só atrapalha quando o SVG vai direto no HTML
+        Ok(match svg.find("<svg") {
+            Some(start) => svg[start..].to_string(),
+            None => svg,
+        })
+    }
+
+    // PNG desenhado num canvas, devolvido como data URL
+    pub fn png_data_url(text: &str, scale: usize) -> Result<String, ApiError> {
+        let code = QrCode::new(text).map_err(|e| ApiError::QrCode(e.to_string()))?;
+        let width = code.width();
+        let size = (width + 2 * QUIET_ZONE) * scale;
+
+        let canvas = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.create_element("canvas").ok())
+            .and_then(|c| c.dyn_into::<HtmlCanvasElement>().ok())
+            .ok_or_else(|| ApiError::BrowserApi("canvas indisponível".to_string()))?;
+        canvas.set_width(size as u32);
+        canvas.set_height(size as u32);
+
+        let context = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|c| c.dyn_into::<CanvasRenderingContext2d>().ok())
+            .ok_or_else(|| ApiError::BrowserApi("canvas 2d indisponível".to_string()))?;
+
+        context.set_fill_style_str("#fff");
+        context.fill_rect(0.0, 0.0, size as f64, size as f64);
+        context.set_fill_style_str("#000");
+        for (index, color) in code.to_colors().into_iter().enumerate() {
+            if color == Color::Dark {
+                let x = (index % width + QUIET_ZONE) * scale;
+                let y = (index / width + QUIET_ZONE) * scale;
+                context.fill_rect(x as f64, y as f64, scale as f64, scale as f64);
+            }
+        }
+
+        canvas
+            .to_data_url_with_type("image/png")
+            .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))
+    }
+}
@@ -0,0 +1,66 @@
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use crate::models::error::ApiError;
+
+// navigator.share ainda é "unstable" no web-sys, por isso a chamada é feita via Reflect
+pub struct ShareService;
+
+impl ShareService {
+    pub async fn copy_text(text: &str) -> Result<(), ApiError> {
+        let window = web_sys::window()
+            .ok_or_else(|| ApiError::BrowserApi("janela indisponível".to_string()))?;
+        JsFuture::from(window.navigator().clipboard().write_text(text))
+            .await
+            .map(|_| ())
+            .map_err(|e| ApiError::BrowserApi(error_message(&e)))
+    }
+
+    // Menu de compartilhamento nativo (celulares e alguns desktops)
+    pub fn can_share() -> bool {
+        web_sys::window()
+            .and_then(|w| Reflect::get(&w.navigator(), &"share".into()).ok())
+            .is_some_and(|share| share.is_function())
+    }
+
+    pub async fn share(title: &str, url: &str) -> Result<(), ApiError> {
+        let navigator = web_sys::window()
+            .ok_or_else(|| ApiError::BrowserApi("janela indisponível".to_string()))?
+            .navigator();
+        let share = Reflect::get(&navigator, &"share".into())
+            .ok()
+            .and_then(|f| f.dyn_into::<Function>().ok())
+            .ok_or_else(|| ApiError::BrowserApi("compartilhamento nativo indisponível".to_string()))?;
+
+        let data = Object::new();
+        let _ = Reflect::set(&data, &"title".into(), &title.into());
+        let _ = Reflect::set(&data, &"url".into(), &url.into());
+
+        let promise = share
+            .call1(&navigator, &data)
+            .map_err(|e| ApiError::BrowserApi(error_message(&e)))?
+            .unchecked_into::<Promise>();
+
+        match JsFuture::from(promise).await {
+            Ok(_) => Ok(()),
+            // O usuário fechou o menu sem escolher nada
+            Err(e) if error_name(&e) == "AbortError" => Err(ApiError::Aborted),
+            Err(e) => Err(ApiError::BrowserApi(error_message(&e))),
+        }
+    }
+}
+
+fn error_name(e: &JsValue) -> String {
+    Reflect::get(e, &"name".into())
+        .ok()
+        .and_then(|n| n.as_string())
+        .unwrap_or_default()
+}
+
+fn error_message(e: &JsValue) -> String {
+    Reflect::get(e, &"message".into())
+        .ok()
+        .and_then(|m| m.as_string())
+        .unwrap_or_else(|| format!("{:?}", e))
+}
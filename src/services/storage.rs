@@ -1,56 +1,105 @@
 use crate::models::file::StoredFile;
-use crate::utils::constants::STORAGE_KEY;
-use web_sys::window;
+use crate::models::settings::Settings;
+use crate::models::upload::UploadSession;
+use crate::utils::constants::{
+    EXPIRED_FILES_KEY, PENDING_DELETIONS_KEY, SETTINGS_KEY, STORAGE_KEY, UPLOAD_SESSIONS_KEY,
+    UPLOAD_SESSION_TTL_MS,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use web_sys::{window, Storage};
 
 pub struct StorageService;
 
 impl StorageService {
-    pub fn load_files() -> Vec<StoredFile> {
-        let window = match window() {
-            Some(w) => w,
-            None => return Vec::new(),
-        };
-
-        let storage = match window.local_storage() {
-            Ok(Some(s)) => s,
-            _ => return Vec::new(),
-        };
-
-        storage
-            .get_item(STORAGE_KEY)
-            .ok()
-            .flatten()
+    fn storage() -> Option<Storage> {
+        window()?.local_storage().ok().flatten()
+    }
+
+    // JSON de uma chave do localStorage; ausente ou inválido vira o padrão
+    fn load_json<T: DeserializeOwned + Default>(key: &str) -> T {
+        Self::storage()
+            .and_then(|storage| storage.get_item(key).ok().flatten())
             .and_then(|data| serde_json::from_str(&data).ok())
             .unwrap_or_default()
     }
 
-    pub fn save_files(files: &[StoredFile]) {
-        let window = match window() {
-            Some(w) => w,
-            None => return,
-        };
-
-        let storage = match window.local_storage() {
-            Ok(Some(s)) => s,
-            _ => return,
-        };
-
-        if let Ok(json) = serde_json::to_string(files) {
-            let _ = storage.set_item(STORAGE_KEY, &json);
+    fn save_json<T: Serialize + ?Sized>(key: &str, value: &T) {
+        if let (Some(storage), Ok(json)) = (Self::storage(), serde_json::to_string(value)) {
+            let _ = storage.set_item(key, &json);
         }
     }
 
-    pub fn clear_files() {
-        let window = match window() {
-            Some(w) => w,
-            None => return,
-        };
+    pub fn load_files() -> Vec<StoredFile> {
+        Self::load_json(STORAGE_KEY)
+    }
+
+    pub fn save_files(files: &[StoredFile]) {
+        Self::save_json(STORAGE_KEY, files)
+    }
+
+    // Arquivos que o servidor confirmou como expirados ou removidos
+    pub fn load_expired_files() -> Vec<StoredFile> {
+        Self::load_json(EXPIRED_FILES_KEY)
+    }
+
+    pub fn save_expired_files(files: &[StoredFile]) {
+        Self::save_json(EXPIRED_FILES_KEY, files)
+    }
+
+    // Arquivos tirados da lista que ainda precisam ser excluídos no servidor
+    pub fn load_pending_deletions() -> Vec<StoredFile> {
+        Self::load_json(PENDING_DELETIONS_KEY)
+    }
 
-        let storage = match window.local_storage() {
-            Ok(Some(s)) => s,
-            _ => return,
-        };
+    pub fn save_pending_deletions(files: &[StoredFile]) {
+        Self::save_json(PENDING_DELETIONS_KEY, files)
+    }
+
+    pub fn load_settings() -> Settings {
+        Self::load_json(SETTINGS_KEY)
+    }
+
+    pub fn save_settings(settings: &Settings) {
+        Self::save_json(SETTINGS_KEY, settings)
+    }
+
+    // Sessões de upload em partes, para retomar depois de recarregar a página
+    fn load_upload_sessions() -> Vec<UploadSession> {
+        Self::load_json(UPLOAD_SESSIONS_KEY)
+    }
+
+    fn save_upload_sessions(sessions: &[UploadSession]) {
+        Self::save_json(UPLOAD_SESSIONS_KEY, sessions)
+    }
+
+    pub fn load_upload_session(fingerprint: &str) -> Option<UploadSession> {
+        Self::load_upload_sessions()
+            .into_iter()
+            .find(|s| s.fingerprint == fingerprint)
+    }
+
+    pub fn save_upload_session(session: &UploadSession) {
+        let mut sessions = Self::load_upload_sessions();
+        sessions.retain(|s| s.fingerprint != session.fingerprint);
+        sessions.push(session.clone());
+        Self::save_upload_sessions(&sessions);
+    }
+
+    // Tira as sessões velhas demais para o backend ainda ter as partes
+    pub fn prune_upload_sessions() {
+        let now = js_sys::Date::now();
+        let mut sessions = Self::load_upload_sessions();
+        let before = sessions.len();
+        sessions.retain(|s| now - s.created_at < UPLOAD_SESSION_TTL_MS);
+        if sessions.len() != before {
+            Self::save_upload_sessions(&sessions);
+        }
+    }
 
-        let _ = storage.remove_item(STORAGE_KEY);
+    pub fn remove_upload_session(fingerprint: &str) {
+        let mut sessions = Self::load_upload_sessions();
+        sessions.retain(|s| s.fingerprint != fingerprint);
+        Self::save_upload_sessions(&sessions);
     }
-}
\ No newline at end of file
+}
@@ -1,52 +1,240 @@
 use yew::prelude::*;
+use std::collections::HashMap;
 use std::rc::Rc;
-use crate::models::file::StoredFile;
+use web_sys::{AbortController, File};
+use crate::models::error::ApiError;
+use crate::models::file::{FileStatus, Integrity, StoredFile};
+use crate::models::operation::Operation;
+use crate::models::settings::{Settings, TokenLock};
+use crate::models::toast::{Removal, Toast, ToastKind};
+use crate::models::upload::{
+    OutboxRecord, ServerCapabilities, TransferProgress, UploadItem, UploadOptions, UploadStatus,
+};
+use crate::services::crypto::{CryptoService, KEY_LEN};
+use crate::services::outbox::OutboxService;
+use crate::services::pwa::PwaService;
 use crate::services::storage::StorageService;
+use crate::utils::constants::{MAX_UPLOAD_ATTEMPTS, UPLOAD_RETRY_BASE_MS, UPLOAD_RETRY_MAX_MS};
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Default)]
 pub struct FilesStore {
     pub files: Vec<StoredFile>,
-    pub loading: bool,
+    pub expired_files: Vec<StoredFile>, // arquivo de links expirados/removidos no servidor
+    pub pending_deletions: Vec<StoredFile>, // fora da lista, aguardando exclusão no servidor
     pub error: Option<String>,
     pub downloading_files: Vec<String>, // lista de file_id em download
+    pub download_errors: HashMap<String, ApiError>, // último erro de download por file_id
+    pub download_progress: HashMap<String, TransferProgress>, // bytes recebidos por file_id
+    pub download_integrity: HashMap<String, Integrity>, // resultado do SHA-256 por file_id
     pub validating: bool, // novo campo para indicar validação
-}
-
-impl Default for FilesStore {
-    fn default() -> Self {
-        Self {
-            files: Vec::new(),
-            loading: false,
-            error: None,
-            downloading_files: Vec::new(),
-            validating: false,
-        }
-    }
+    pub uploads: Vec<UploadItem>, // fila de upload
+    pub next_upload_id: u32,
+    pub settings: Settings,
+    pub abort_handles: HashMap<Operation, AbortController>, // operações canceláveis
+    pub capabilities: ServerCapabilities,
+    pub capabilities_loaded: bool, // a fila só anda depois da consulta ao backend
+    pub upload_options: UploadOptions,
+    pub online: bool, // navigator.onLine; sem conexão a fila de upload fica parada
+    pub toasts: Vec<Toast>,
+    pub next_toast_id: u32,
+    pub owner_key: Option<[u8; KEY_LEN]>, // chave dos tokens de dono, só em memória
 }
 
 impl FilesStore {
     pub fn load() -> Self {
+        let settings = StorageService::load_settings();
+        let upload_options = UploadOptions {
+            encrypt: settings.encrypt_by_default,
+            ..UploadOptions::default()
+        };
+
         Self {
             files: StorageService::load_files(),
-            loading: false,
-            error: None,
-            downloading_files: Vec::new(),
+            expired_files: StorageService::load_expired_files(),
+            pending_deletions: StorageService::load_pending_deletions(),
             validating: true, // marca como validando ao carregar
+            settings,
+            upload_options,
+            online: PwaService::is_online(),
+            ..Self::default()
+        }
+    }
+
+    pub fn active_uploads(&self) -> usize {
+        self.uploads
+            .iter()
+            .filter(|u| u.status.is_running())
+            .count()
+    }
+
+    fn push_upload(&mut self, file: File, options: UploadOptions, key: String) -> &mut UploadItem {
+        let id = self.next_upload_id;
+        self.next_upload_id += 1;
+        self.uploads.push(UploadItem {
+            id,
+            file,
+            status: UploadStatus::Queued,
+            progress: None,
+            started_at: None,
+            options,
+            key,
+            attempts: 0,
+        });
+        self.uploads.last_mut().expect("item recém-inserido")
+    }
+
+    // Arquivos da lista e exclusões pendentes: os dois guardam tokens de dono
+    fn owned_files(&mut self) -> impl Iterator<Item = &mut StoredFile> {
+        self.files.iter_mut().chain(self.pending_deletions.iter_mut())
+    }
+
+    // Com o bloqueio ligado, os tokens de dono só vão para o disco cifrados.
+    // Tokens recebidos enquanto bloqueado ficam só em memória até desbloquear.
+    fn save_files(&mut self) {
+        if self.settings.token_lock.is_none() {
+            StorageService::save_files(&self.files);
+            StorageService::save_pending_deletions(&self.pending_deletions);
+            return;
+        }
+
+        if let Some(key) = self.owner_key {
+            for file in self.owned_files() {
+                if let Some(token) = &file.deletion_token
+                    && file.sealed_token.is_none()
+                {
+                    file.sealed_token = CryptoService::seal_string(token, &key).ok();
+                }
+            }
+        }
+
+        let without_token = |files: &[StoredFile]| -> Vec<StoredFile> {
+            files
+                .iter()
+                .cloned()
+                .map(|mut f| {
+                    f.deletion_token = None;
+                    f
+                })
+                .collect()
+        };
+        StorageService::save_files(&without_token(&self.files));
+        StorageService::save_pending_deletions(&without_token(&self.pending_deletions));
+    }
+
+    fn push_toast(&mut self, kind: ToastKind, message: String, undo: Option<Removal>) {
+        let id = self.next_toast_id;
+        self.next_toast_id += 1;
+        self.toasts.push(Toast { id, kind, message, undo });
+    }
+
+    // Tira arquivos da lista guardando a posição, e oferece "Desfazer"
+    fn remove_files(&mut self, file_ids: &[String], delete_on_server: bool) {
+        let mut removed = Vec::new();
+        let mut index = 0;
+        self.files.retain(|f| {
+            let keep = !file_ids.contains(&f.file_id);
+            if !keep {
+                removed.push((index, f.clone()));
+            }
+            index += 1;
+            keep
+        });
+        if removed.is_empty() {
+            return;
+        }
+        for (_, file) in &removed {
+            self.download_errors.remove(&file.file_id);
+        }
+        // A exclusão fica registrada no disco desde já: se a página fechar
+        // antes do aviso sumir, ela é feita na próxima abertura
+        if delete_on_server {
+            self.pending_deletions.extend(
+                removed
+                    .iter()
+                    .map(|(_, f)| f.clone())
+                    .filter(|f| f.deletion_token.is_some() || f.sealed_token.is_some()),
+            );
+        }
+        self.save_files();
+
+        let message = match (removed.as_slice(), delete_on_server) {
+            ([(_, file)], true) => format!("“{}” será excluído do servidor", file.filename),
+            ([(_, file)], false) => format!("“{}” removido da lista", file.filename),
+            (files, _) => format!("{} arquivos removidos da lista", files.len()),
+        };
+        let removal = Removal {
+            files: removed,
+            delete_on_server,
+        };
+        self.push_toast(ToastKind::Success, message, Some(removal));
+    }
+
+    // Devolve arquivos às posições de onde saíram
+    fn restore_files(&mut self, mut files: Vec<(usize, StoredFile)>) {
+        files.sort_by_key(|(index, _)| *index);
+        self.pending_deletions
+            .retain(|p| !files.iter().any(|(_, f)| f.file_id == p.file_id));
+        for (index, file) in files {
+            if self.files.iter().any(|f| f.file_id == file.file_id) {
+                continue;
+            }
+            let index = index.min(self.files.len());
+            self.files.insert(index, file);
+        }
+        self.save_files();
+    }
+
+    // Itens em espera voltam para a fila; `force` ignora o horário agendado
+    fn wake_retries(&mut self, force: bool) {
+        let now = js_sys::Date::now();
+        for item in &mut self.uploads {
+            if let UploadStatus::Retrying(at) = item.status
+                && (force || at <= now)
+            {
+                item.status = UploadStatus::Queued;
+            }
         }
     }
 }
 
-#[allow(dead_code)]
 pub enum FilesStoreAction {
     AddFile(StoredFile),
-    RemoveFile(String),
+    RemoveFile(String, bool), // file_id, excluir também no servidor
     ClearAll,
-    SetLoading(bool),
+    UndoRemoval(u32),         // id do aviso de remoção
+    RestoreFiles(Vec<(usize, StoredFile)>),
+    FinishDeletion(String), // file_id, excluído no servidor
     SetError(Option<String>),
-    StartDownload(String),   // file_id
+    StartDownload(String, AbortController), // file_id
     EndDownload(String),     // file_id
-    SetValidatedFiles(Vec<StoredFile>), // nova action
+    SetDownloadError(String, Option<ApiError>), // file_id
+    SetDownloadProgress(String, u64, u64), // file_id, bytes recebidos, total
+    SetIntegrity(String, Integrity),       // file_id
+    FileValidated(String, FileStatus), // file_id
+    ClearExpired,
     SetValidating(bool), // nova action
+    EnqueueUploads(Vec<File>),
+    StartUpload(u32, AbortController),  // id do item na fila
+    SetUploadStatus(u32, UploadStatus), // id do item na fila
+    SetUploadProgress(u32, u64, u64),   // id, bytes enviados, total
+    UploadFailed(u32, ApiError),        // decide entre nova tentativa e falha definitiva
+    RetryUpload(u32),
+    DiscardUpload(u32),
+    WakeRetries,
+    RestoreUploads(Vec<OutboxRecord>),
+    ClearFinishedUploads,
+    UpdateSettings(Settings),
+    CancelOperation(Operation),
+    SetCapabilities(ServerCapabilities),
+    SetUploadOptions(UploadOptions),
+    SetOnline(bool),
+    UpdateFile(StoredFile),
+    EnableTokenLock(TokenLock, [u8; KEY_LEN]),
+    UnlockTokens([u8; KEY_LEN]),
+    LockTokens,
+    DisableTokenLock,
+    ShowToast(ToastKind, String),
+    DismissToast(u32),
 }
 
 impl Reducible for FilesStore {
@@ -54,45 +242,320 @@ impl Reducible for FilesStore {
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
         let mut new_store = (*self).clone();
-        
+
         match action {
             FilesStoreAction::AddFile(file) => {
                 new_store.files.insert(0, file);
-                StorageService::save_files(&new_store.files);
+                new_store.save_files();
             }
-            FilesStoreAction::RemoveFile(file_id) => {
-                new_store.files.retain(|f| f.file_id != file_id);
-                StorageService::save_files(&new_store.files);
+            FilesStoreAction::RemoveFile(file_id, delete_on_server) => {
+                new_store.remove_files(&[file_id], delete_on_server);
             }
             FilesStoreAction::ClearAll => {
-                new_store.files.clear();
-                StorageService::clear_files();
+                let file_ids: Vec<String> = new_store.files.iter().map(|f| f.file_id.clone()).collect();
+                new_store.remove_files(&file_ids, false);
+            }
+            FilesStoreAction::UndoRemoval(toast_id) => {
+                if let Some(index) = new_store.toasts.iter().position(|t| t.id == toast_id) {
+                    let toast = new_store.toasts.remove(index);
+                    if let Some(removal) = toast.undo {
+                        new_store.restore_files(removal.files);
+                    }
+                }
             }
-            FilesStoreAction::SetLoading(loading) => {
-                new_store.loading = loading;
+            FilesStoreAction::RestoreFiles(files) => {
+                new_store.restore_files(files);
+            }
+            FilesStoreAction::FinishDeletion(file_id) => {
+                new_store.pending_deletions.retain(|f| f.file_id != file_id);
+                new_store.save_files();
             }
             FilesStoreAction::SetError(error) => {
                 new_store.error = error;
             }
-            FilesStoreAction::StartDownload(file_id) => {
+            FilesStoreAction::StartDownload(file_id, controller) => {
+                new_store.download_errors.remove(&file_id);
                 if !new_store.downloading_files.contains(&file_id) {
-                    new_store.downloading_files.push(file_id);
+                    new_store.downloading_files.push(file_id.clone());
                 }
+                new_store.abort_handles.insert(Operation::Download(file_id), controller);
             }
             FilesStoreAction::EndDownload(file_id) => {
                 new_store.downloading_files.retain(|id| id != &file_id);
+                new_store.download_progress.remove(&file_id);
+                new_store.abort_handles.remove(&Operation::Download(file_id));
+            }
+            FilesStoreAction::SetDownloadError(file_id, error) => match error {
+                Some(error) => {
+                    new_store.download_errors.insert(file_id, error);
+                }
+                None => {
+                    new_store.download_errors.remove(&file_id);
+                }
+            },
+            FilesStoreAction::FileValidated(file_id, status) => {
+                match status {
+                    // Removido no servidor: sai da lista e vai para o arquivo
+                    FileStatus::Gone => {
+                        if let Some(index) = new_store.files.iter().position(|f| f.file_id == file_id) {
+                            let mut file = new_store.files.remove(index);
+                            file.stale = false;
+                            // Token de um arquivo que não existe mais não serve para nada
+                            file.deletion_token = None;
+                            file.sealed_token = None;
+                            new_store.expired_files.retain(|f| f.file_id != file_id);
+                            new_store.expired_files.insert(0, file);
+                            StorageService::save_expired_files(&new_store.expired_files);
+                        }
+                    }
+                    // Sem resposta conclusiva o arquivo fica, só marcado
+                    FileStatus::Alive | FileStatus::Unknown => {
+                        let stale = status == FileStatus::Unknown;
+                        if let Some(file) = new_store.files.iter_mut().find(|f| f.file_id == file_id) {
+                            file.stale = stale;
+                        }
+                    }
+                }
+                new_store.save_files();
             }
-            FilesStoreAction::SetValidatedFiles(files) => {
-                new_store.files = files;
-                StorageService::save_files(&new_store.files);
+            FilesStoreAction::ClearExpired => {
+                new_store.expired_files.clear();
+                StorageService::save_expired_files(&new_store.expired_files);
             }
             FilesStoreAction::SetValidating(validating) => {
                 new_store.validating = validating;
             }
+            FilesStoreAction::EnqueueUploads(files) => {
+                let options = new_store.upload_options.clone();
+                // Vale para todas as entradas (botão, arrastar, colar, compartilhar):
+                // com a senha vazia o arquivo seria cifrado com "" e nunca abriria
+                if options.password.as_deref() == Some("") {
+                    let message = "Digite a senha de proteção (ou desligue a opção) antes de enviar".to_string();
+                    new_store.push_toast(ToastKind::Error, message, None);
+                    return Rc::new(new_store);
+                }
+                for file in files {
+                    let item = new_store.push_upload(file, options.clone(), OutboxService::new_key());
+                    OutboxService::save(item);
+                }
+            }
+            FilesStoreAction::RestoreUploads(records) => {
+                for record in records {
+                    if new_store.uploads.iter().any(|u| u.key == record.key) {
+                        continue;
+                    }
+                    let options = UploadOptions {
+                        encrypt: record.encrypt,
+                        password: None,
+                        policy: record.policy,
+                    };
+                    let item = new_store.push_upload(record.file, options, record.key);
+                    item.attempts = record.attempts;
+                    if let Some(error) = record.error {
+                        item.status = UploadStatus::Failed(error);
+                    }
+                }
+            }
+            FilesStoreAction::StartUpload(id, controller) => {
+                if let Some(item) = new_store.uploads.iter_mut().find(|u| u.id == id) {
+                    item.set_status(UploadStatus::Uploading);
+                    new_store.abort_handles.insert(Operation::Upload(id), controller);
+                }
+            }
+            FilesStoreAction::SetUploadStatus(id, status) => {
+                if !status.is_active() {
+                    new_store.abort_handles.remove(&Operation::Upload(id));
+                }
+                // Um item cancelado não volta a andar
+                if let Some(item) = new_store
+                    .uploads
+                    .iter_mut()
+                    .find(|u| u.id == id && u.status != UploadStatus::Cancelled)
+                {
+                    item.set_status(status);
+                    if item.status == UploadStatus::Done {
+                        OutboxService::remove(&item.key);
+                    }
+                }
+            }
+            FilesStoreAction::UploadFailed(id, error) => {
+                new_store.abort_handles.remove(&Operation::Upload(id));
+                if let Some(item) = new_store
+                    .uploads
+                    .iter_mut()
+                    .find(|u| u.id == id && u.status != UploadStatus::Cancelled)
+                {
+                    item.attempts += 1;
+                    item.progress = None;
+                    // Espera exponencial: 2s, 4s, 8s... até 5 minutos
+                    item.status = if error.is_transient() && item.attempts < MAX_UPLOAD_ATTEMPTS {
+                        let delay = UPLOAD_RETRY_BASE_MS * 2f64.powi(item.attempts as i32 - 1);
+                        UploadStatus::Retrying(js_sys::Date::now() + delay.min(UPLOAD_RETRY_MAX_MS))
+                    } else {
+                        UploadStatus::Failed(error.to_string())
+                    };
+                    OutboxService::save(item);
+                }
+            }
+            FilesStoreAction::RetryUpload(id) => {
+                if let Some(item) = new_store
+                    .uploads
+                    .iter_mut()
+                    .find(|u| u.id == id && !u.status.is_running())
+                {
+                    item.status = UploadStatus::Queued;
+                    item.attempts = 0;
+                    item.progress = None;
+                    OutboxService::save(item);
+                }
+            }
+            FilesStoreAction::DiscardUpload(id) => {
+                if let Some(controller) = new_store.abort_handles.remove(&Operation::Upload(id)) {
+                    controller.abort();
+                }
+                if let Some(index) = new_store.uploads.iter().position(|u| u.id == id) {
+                    let item = new_store.uploads.remove(index);
+                    OutboxService::remove(&item.key);
+                }
+            }
+            FilesStoreAction::WakeRetries => {
+                new_store.wake_retries(false);
+            }
+            FilesStoreAction::SetUploadProgress(id, loaded, total) => {
+                if let Some(item) = new_store.uploads.iter_mut().find(|u| u.id == id) {
+                    let now = js_sys::Date::now();
+                    let started_at = item.started_at.unwrap_or(now);
+                    let resumed = item.progress.as_ref().map_or(loaded, |p| p.resumed);
+                    item.progress = Some(TransferProgress {
+                        loaded,
+                        total,
+                        resumed,
+                        started_at,
+                        updated_at: now,
+                    });
+                }
+            }
+            FilesStoreAction::SetDownloadProgress(file_id, loaded, total) => {
+                // Chega depois do cancelamento? Não recria o progresso
+                if new_store.downloading_files.contains(&file_id) {
+                    let now = js_sys::Date::now();
+                    let previous = new_store.download_progress.get(&file_id);
+                    let started_at = previous.map_or(now, |p| p.started_at);
+                    let resumed = previous.map_or(loaded, |p| p.resumed);
+                    new_store.download_progress.insert(
+                        file_id,
+                        TransferProgress {
+                            loaded,
+                            total,
+                            resumed,
+                            started_at,
+                            updated_at: now,
+                        },
+                    );
+                }
+            }
+            FilesStoreAction::SetIntegrity(file_id, integrity) => {
+                new_store.download_integrity.insert(file_id, integrity);
+            }
+            FilesStoreAction::ClearFinishedUploads => {
+                for item in new_store.uploads.iter().filter(|u| !u.status.is_active()) {
+                    OutboxService::remove(&item.key);
+                }
+                new_store.uploads.retain(|u| u.status.is_active());
+            }
+            FilesStoreAction::UpdateSettings(mut settings) => {
+                settings.max_concurrent_uploads = settings.max_concurrent_uploads.max(1);
+                StorageService::save_settings(&settings);
+                new_store.settings = settings;
+            }
+            FilesStoreAction::SetCapabilities(capabilities) => {
+                new_store.capabilities = capabilities;
+                new_store.capabilities_loaded = true;
+            }
+            FilesStoreAction::SetUploadOptions(options) => {
+                new_store.upload_options = options;
+            }
+            FilesStoreAction::SetOnline(online) => {
+                new_store.online = online;
+                // A conexão voltou: não espera o fim do intervalo de nova tentativa
+                if online {
+                    new_store.wake_retries(true);
+                }
+            }
+            FilesStoreAction::UpdateFile(file) => {
+                if let Some(current) = new_store.files.iter_mut().find(|f| f.file_id == file.file_id) {
+                    *current = file;
+                }
+                new_store.save_files();
+            }
+            FilesStoreAction::EnableTokenLock(lock, key) => {
+                new_store.settings.token_lock = Some(lock);
+                new_store.owner_key = Some(key);
+                StorageService::save_settings(&new_store.settings);
+                new_store.save_files();
+            }
+            FilesStoreAction::UnlockTokens(key) => {
+                for file in new_store.owned_files() {
+                    if let Some(sealed) = &file.sealed_token
+                        && let Ok(token) = CryptoService::open_string(sealed, &key)
+                    {
+                        file.deletion_token = Some(token);
+                    }
+                }
+                new_store.owner_key = Some(key);
+                new_store.save_files();
+            }
+            FilesStoreAction::LockTokens => {
+                new_store.owner_key = None;
+                for file in new_store.owned_files() {
+                    if file.sealed_token.is_some() {
+                        file.deletion_token = None;
+                    }
+                }
+            }
+            // Só faz sentido desbloqueado: os tokens voltam ao disco em texto puro
+            FilesStoreAction::DisableTokenLock => {
+                if new_store.owner_key.take().is_some() {
+                    for file in new_store.owned_files() {
+                        file.sealed_token = None;
+                    }
+                    new_store.settings.token_lock = None;
+                    StorageService::save_settings(&new_store.settings);
+                    new_store.save_files();
+                }
+            }
+            FilesStoreAction::ShowToast(kind, message) => {
+                new_store.push_toast(kind, message, None);
+            }
+            FilesStoreAction::DismissToast(id) => {
+                new_store.toasts.retain(|t| t.id != id);
+            }
+            FilesStoreAction::CancelOperation(operation) => {
+                // Cancelamento não é erro: só limpa o estado da operação
+                if let Some(controller) = new_store.abort_handles.remove(&operation) {
+                    controller.abort();
+                }
+                match operation {
+                    Operation::Upload(id) => {
+                        if let Some(item) = new_store
+                            .uploads
+                            .iter_mut()
+                            .find(|u| u.id == id && u.status.is_active())
+                        {
+                            item.status = UploadStatus::Cancelled;
+                            OutboxService::remove(&item.key);
+                        }
+                    }
+                    Operation::Download(file_id) => {
+                        new_store.downloading_files.retain(|id| id != &file_id);
+                        new_store.download_progress.remove(&file_id);
+                    }
+                }
+            }
         }
-        
+
         Rc::new(new_store)
     }
 }
 
-pub type FilesStoreContext = UseReducerHandle<FilesStore>;
\ No newline at end of file
+pub type FilesStoreContext = UseReducerHandle<FilesStore>;
@@ -1,6 +1,49 @@
-
-
-pub const API_URL: &str = "https://quickshare-latest.onrender.com";
+// Backend usado quando /config.json não existe ou é inválido.
+// Pode ser trocado no build: `API_URL=https://... trunk build --release`;
+// vazio (ex.: `ARG` sem valor no Docker) conta como não definido
+pub const FALLBACK_API_URL: &str = "https://quickshare-latest.onrender.com";
+pub const DEFAULT_API_URL: &str = match option_env!("API_URL") {
+    Some(url) if !url.is_empty() => url,
+    _ => FALLBACK_API_URL,
+};
+pub const CONFIG_PATH: &str = "/config.json";
 pub const STORAGE_KEY: &str = "quickshare_uploaded_files";
-
-// pub const API_URL: &str = env!("API_URL");
\ No newline at end of file
+pub const EXPIRED_FILES_KEY: &str = "quickshare_expired_files";
+pub const PENDING_DELETIONS_KEY: &str = "quickshare_pending_deletions";
+pub const UPLOAD_SESSIONS_KEY: &str = "quickshare_upload_sessions";
+pub const SETTINGS_KEY: &str = "quickshare_settings";
+pub const DEFAULT_MAX_CONCURRENT_UPLOADS: usize = 3;
+pub const RECENT_FILES_ON_HOME: usize = 3;
+// Validades oferecidas no envio, em segundos
+pub const EXPIRY_CHOICES: &[(u64, &str)] = &[(3600, "1 hora"), (86400, "24 horas"), (7 * 86400, "7 dias")];
+pub const DEFAULT_EXPIRES_IN: u64 = 86400;
+// Limites de download oferecidos; 1 = apagar após o primeiro download
+pub const DOWNLOAD_LIMIT_CHOICES: &[u32] = &[1, 5, 10, 25];
+pub const DEFAULT_CHUNK_SIZE: u64 = 5 * 1024 * 1024;
+// Teto para o tamanho de parte anunciado pelo servidor; cada parte fica inteira na memória
+pub const MAX_CHUNK_SIZE: u64 = 100 * 1024 * 1024;
+pub const MAX_CHUNK_RETRIES: u32 = 4;
+// Sessões de upload em partes guardadas por mais tempo que isso são descartadas
+pub const UPLOAD_SESSION_TTL_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+pub const MAX_CONCURRENT_VALIDATIONS: usize = 4;
+// Tamanho das leituras ao calcular o SHA-256 de um arquivo
+pub const HASH_CHUNK_BYTES: f64 = 4.0 * 1024.0 * 1024.0;
+// Pré-visualização: texto mostra só o começo; mídia e PDF vêm inteiros até o limite
+pub const PREVIEW_TEXT_BYTES: u64 = 64 * 1024;
+pub const PREVIEW_MAX_BYTES: u64 = 50 * 1024 * 1024;
+// Intervalo mínimo entre atualizações de progresso do download
+pub const DOWNLOAD_PROGRESS_INTERVAL_MS: f64 = 100.0;
+pub const TOAST_DURATION_MS: u32 = 4_000;
+pub const UNDO_DURATION_MS: u32 = 6_000;
+pub const MAX_UPLOAD_ATTEMPTS: u32 = 8;
+pub const UPLOAD_RETRY_BASE_MS: f64 = 2_000.0;
+pub const UPLOAD_RETRY_MAX_MS: f64 = 5.0 * 60.0 * 1000.0;
+pub const IDB_NAME: &str = "quickshare";
+// Mantenha em sincronia com DB_VERSION/DB_STORES em sw.js
+pub const IDB_VERSION: u32 = 3;
+pub const OUTBOX_STORE: &str = "outbox";
+pub const SHARE_INBOX_STORE: &str = "share_inbox";
+pub const PARTIAL_DOWNLOADS_STORE: &str = "partial_downloads";
+pub const IDB_STORES: &[&str] = &[OUTBOX_STORE, SHARE_INBOX_STORE, PARTIAL_DOWNLOADS_STORE];
+// Downloads interrompidos são salvos a cada tantos bytes para poder retomar
+pub const DOWNLOAD_CHECKPOINT_BYTES: u64 = 8 * 1024 * 1024;
@@ -0,0 +1,35 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Blob, BlobPropertyBag, HtmlElement, Url};
+use crate::models::error::ApiError;
+
+// Dispara o "salvar como" do navegador para um endereço (blob: ou data:)
+pub fn save_url(url: &str, filename: &str) -> Result<(), ApiError> {
+    let anchor = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.create_element("a").ok())
+        .and_then(|a| a.dyn_into::<HtmlElement>().ok())
+        .ok_or_else(|| ApiError::BrowserApi("documento indisponível".to_string()))?;
+
+    let _ = anchor.set_attribute("href", url);
+    let _ = anchor.set_attribute("download", filename);
+    anchor.click();
+    Ok(())
+}
+
+pub fn save_text(text: &str, mime: &str, filename: &str) -> Result<(), ApiError> {
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let parts = js_sys::Array::of1(&text.into());
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &options)
+        .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+
+    save_blob(&blob, filename)
+}
+
+pub fn save_blob(blob: &Blob, filename: &str) -> Result<(), ApiError> {
+    let url = Url::create_object_url_with_blob(blob)
+        .map_err(|e| ApiError::BrowserApi(format!("{:?}", e)))?;
+    let result = save_url(&url, filename);
+    let _ = Url::revoke_object_url(&url);
+    result
+}
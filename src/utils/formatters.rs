@@ -1,3 +1,5 @@
+use crate::utils::constants::EXPIRY_CHOICES;
+
 pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
@@ -14,6 +16,25 @@ pub fn format_bytes(bytes: u64) -> String {
     }
 }
 
+pub fn format_throughput(bytes_per_sec: f64) -> String {
+    format!("{}/s", format_bytes(bytes_per_sec as u64))
+}
+
+pub fn format_duration(secs: f64) -> String {
+    let secs = secs.round() as u64;
+    let (d, h, m, s) = (secs / 86400, (secs % 86400) / 3600, (secs % 3600) / 60, secs % 60);
+
+    if d > 0 {
+        format!("{}d {:02}h", d, h)
+    } else if h > 0 {
+        format!("{}h {:02}min", h, m)
+    } else if m > 0 {
+        format!("{}min {:02}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
 pub fn format_expires(expires_at: &str) -> String {
     expires_at
         .split('T')
@@ -22,7 +43,6 @@ pub fn format_expires(expires_at: &str) -> String {
         .to_string()
 }
 
-#[allow(unused)]
 pub fn format_datetime(datetime: &str) -> String {
     if let Some(date_part) = datetime.split('T').next() {
         if let Some(time_part) = datetime.split('T').nth(1) {
@@ -33,3 +53,20 @@ pub fn format_datetime(datetime: &str) -> String {
     }
     datetime.to_string()
 }
+
+// Validade escolhida no envio ("24 horas"), ou a duração quando não é uma das opções
+pub fn format_expiry(expires_in: u64) -> String {
+    EXPIRY_CHOICES
+        .iter()
+        .find(|(secs, _)| *secs == expires_in)
+        .map(|(_, label)| label.to_string())
+        .unwrap_or_else(|| format_duration(expires_in as f64))
+}
+
+pub fn format_download_limit(max_downloads: Option<u32>) -> String {
+    match max_downloads {
+        None => "Sem limite".to_string(),
+        Some(1) => "Apagar após o 1º download".to_string(),
+        Some(n) => format!("Até {} downloads", n),
+    }
+}
@@ -0,0 +1,51 @@
+use crate::models::file::StoredFile;
+
+// O que vem no #fragmento do link compartilhado. O fragmento nunca é enviado
+// ao servidor, então é o único lugar seguro para a chave.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ShareSecret {
+    pub key: Option<String>,
+    pub password_protected: bool,
+}
+
+impl ShareSecret {
+    pub fn from_file(file: &StoredFile) -> Self {
+        Self {
+            key: file.encryption_key.clone(),
+            password_protected: file.password_protected,
+        }
+    }
+
+    pub fn to_fragment(&self) -> String {
+        match &self.key {
+            Some(key) => format!("#k={}", key),
+            None if self.password_protected => "#p=1".to_string(),
+            None => String::new(),
+        }
+    }
+
+    pub fn parse(hash: &str) -> Self {
+        let mut secret = Self::default();
+        for pair in hash.trim_start_matches('#').split('&') {
+            match pair.split_once('=') {
+                Some(("k", key)) if !key.is_empty() => secret.key = Some(key.to_string()),
+                Some(("p", "1")) => secret.password_protected = true,
+                _ => {}
+            }
+        }
+        secret
+    }
+}
+
+// Link para a página do destinatário (/f/:file_id), no mesmo domínio do app
+pub fn share_url(file: &StoredFile) -> String {
+    let origin = web_sys::window()
+        .and_then(|w| w.location().origin().ok())
+        .unwrap_or_default();
+    format!(
+        "{}/f/{}{}",
+        origin,
+        file.file_id,
+        ShareSecret::from_file(file).to_fragment()
+    )
+}
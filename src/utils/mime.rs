@@ -0,0 +1,213 @@
+// Tipo do conteúdo detectado pelos primeiros bytes (assinatura), sem confiar
+// na extensão do nome do arquivo
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PreviewKind {
+    Image,
+    Text,
+    Audio,
+    Video,
+    Pdf,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Detected {
+    pub kind: PreviewKind,
+    pub mime: &'static str,
+}
+
+// Quantos bytes do início bastam para `detect`
+pub const SNIFF_LEN: usize = 4096;
+
+pub fn detect(bytes: &[u8]) -> Option<Detected> {
+    use PreviewKind::*;
+
+    let found = |kind, mime| Some(Detected { kind, mime });
+    let at = |offset: usize, magic: &[u8]| bytes.get(offset..offset + magic.len()) == Some(magic);
+
+    if at(0, b"\x89PNG\r\n\x1a\n") {
+        return found(Image, "image/png");
+    }
+    if at(0, b"\xff\xd8\xff") {
+        return found(Image, "image/jpeg");
+    }
+    if at(0, b"GIF87a") || at(0, b"GIF89a") {
+        return found(Image, "image/gif");
+    }
+    if at(0, b"RIFF") && at(8, b"WEBP") {
+        return found(Image, "image/webp");
+    }
+    if at(0, b"BM") && is_bmp_header(bytes) {
+        return found(Image, "image/bmp");
+    }
+    if at(0, b"%PDF-") {
+        return found(Pdf, "application/pdf");
+    }
+    if at(0, b"RIFF") && at(8, b"WAVE") {
+        return found(Audio, "audio/wav");
+    }
+    if at(0, b"ID3") || is_mpeg_frame(bytes) {
+        return found(Audio, "audio/mpeg");
+    }
+    if at(0, b"fLaC") {
+        return found(Audio, "audio/flac");
+    }
+    if at(0, b"OggS") {
+        return found(Audio, "audio/ogg");
+    }
+    if at(0, b"\x1a\x45\xdf\xa3") {
+        return found(Video, "video/webm");
+    }
+    // ISO BMFF (MP4/MOV/M4A): a marca vem logo depois de "ftyp"
+    if at(4, b"ftyp") {
+        if at(8, b"M4A ") || at(8, b"M4B ") {
+            return found(Audio, "audio/mp4");
+        }
+        if at(8, b"qt  ") {
+            return found(Video, "video/quicktime");
+        }
+        return found(Video, "video/mp4");
+    }
+
+    is_text(bytes).then_some(Detected { kind: Text, mime: "text/plain;charset=utf-8" })
+}
+
+// "BM" sozinho é comum em texto; o tamanho do cabeçalho DIB que vem depois
+// do cabeçalho de arquivo precisa ser um dos formatos conhecidos
+fn is_bmp_header(bytes: &[u8]) -> bool {
+    match bytes.get(14..18) {
+        Some(dib) => matches!(u32::from_le_bytes([dib[0], dib[1], dib[2], dib[3]]), 12 | 40 | 108 | 124),
+        None => false,
+    }
+}
+
+// Cabeçalho de frame MPEG de áudio sem ID3: além dos 11 bits de sincronia,
+// versão, camada, bitrate e taxa de amostragem precisam ser válidos
+fn is_mpeg_frame(bytes: &[u8]) -> bool {
+    let [b0, b1, b2, ..] = *bytes else {
+        return false;
+    };
+    let version = (b1 >> 3) & 0b11;
+    let layer = (b1 >> 1) & 0b11;
+    let bitrate = b2 >> 4;
+    let sample_rate = (b2 >> 2) & 0b11;
+    b0 == 0xff && b1 & 0xe0 == 0xe0 && version != 0b01 && layer != 0 && bitrate != 0 && bitrate != 0xf && sample_rate != 0b11
+}
+
+// UTF-8 válido e sem bytes de controle binários; um caractere cortado no fim
+// da amostra não conta como erro
+fn is_text(bytes: &[u8]) -> bool {
+    if bytes.is_empty() {
+        return false;
+    }
+
+    let valid = match std::str::from_utf8(bytes) {
+        Ok(_) => true,
+        Err(e) => e.error_len().is_none(),
+    };
+    valid && !bytes.iter().any(|&b| b < 0x20 && !matches!(b, b'\t' | b'\n' | b'\r' | 0x0c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind_of(bytes: &[u8]) -> Option<(PreviewKind, &'static str)> {
+        detect(bytes).map(|d| (d.kind, d.mime))
+    }
+
+    // Cabeçalho de arquivo BMP seguido do tamanho do cabeçalho DIB
+    fn bmp(dib_size: u32) -> Vec<u8> {
+        let mut bytes = b"BM".to_vec();
+        bytes.extend([0; 12]);
+        bytes.extend(dib_size.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn detects_images() {
+        assert_eq!(kind_of(b"\x89PNG\r\n\x1a\n\0\0"), Some((PreviewKind::Image, "image/png")));
+        assert_eq!(kind_of(b"\xff\xd8\xff\xe0"), Some((PreviewKind::Image, "image/jpeg")));
+        assert_eq!(kind_of(b"GIF87a"), Some((PreviewKind::Image, "image/gif")));
+        assert_eq!(kind_of(b"GIF89a"), Some((PreviewKind::Image, "image/gif")));
+        assert_eq!(kind_of(b"RIFF\0\0\0\0WEBPVP8 "), Some((PreviewKind::Image, "image/webp")));
+        for size in [12, 40, 108, 124] {
+            assert_eq!(kind_of(&bmp(size)), Some((PreviewKind::Image, "image/bmp")));
+        }
+    }
+
+    #[test]
+    fn bmp_requires_known_dib_header() {
+        let mut bytes = bmp(7);
+        bytes[2..14].copy_from_slice(b"ad hoc notes");
+        assert_ne!(detect(&bytes).map(|d| d.mime), Some("image/bmp"));
+        assert_eq!(kind_of(b"BMW 320i"), Some((PreviewKind::Text, "text/plain;charset=utf-8")));
+    }
+
+    #[test]
+    fn detects_pdf() {
+        assert_eq!(kind_of(b"%PDF-1.7\n"), Some((PreviewKind::Pdf, "application/pdf")));
+    }
+
+    #[test]
+    fn detects_audio() {
+        assert_eq!(kind_of(b"RIFF\0\0\0\0WAVEfmt "), Some((PreviewKind::Audio, "audio/wav")));
+        assert_eq!(kind_of(b"ID3\x04\0"), Some((PreviewKind::Audio, "audio/mpeg")));
+        // MPEG-1 camada III, 128 kbps, 44,1 kHz
+        assert_eq!(kind_of(b"\xff\xfb\x90\x64"), Some((PreviewKind::Audio, "audio/mpeg")));
+        assert_eq!(kind_of(b"fLaC\0\0\0\x22"), Some((PreviewKind::Audio, "audio/flac")));
+        assert_eq!(kind_of(b"OggS\0\x02"), Some((PreviewKind::Audio, "audio/ogg")));
+        assert_eq!(kind_of(b"\0\0\0\x20ftypM4A \0\0"), Some((PreviewKind::Audio, "audio/mp4")));
+    }
+
+    #[test]
+    fn mpeg_frame_requires_valid_fields() {
+        // Versão reservada
+        assert_eq!(detect(b"\xff\xeb\x90\x64"), None);
+        // Camada reservada
+        assert_eq!(detect(b"\xff\xf9\x90\x64"), None);
+        // Bitrate livre e inválido
+        assert_eq!(detect(b"\xff\xfb\x00\x64"), None);
+        assert_eq!(detect(b"\xff\xfb\xf0\x64"), None);
+        // Taxa de amostragem reservada
+        assert_eq!(detect(b"\xff\xfb\x9c\x64"), None);
+        // Curto demais
+        assert_eq!(detect(b"\xff\xfb"), None);
+    }
+
+    #[test]
+    fn detects_video() {
+        assert_eq!(kind_of(b"\x1a\x45\xdf\xa3\x9f"), Some((PreviewKind::Video, "video/webm")));
+        assert_eq!(kind_of(b"\0\0\0\x18ftypisom\0\0"), Some((PreviewKind::Video, "video/mp4")));
+        assert_eq!(kind_of(b"\0\0\0\x14ftypqt  \0\0"), Some((PreviewKind::Video, "video/quicktime")));
+    }
+
+    #[test]
+    fn detects_text() {
+        let text = Some((PreviewKind::Text, "text/plain;charset=utf-8"));
+        assert_eq!(kind_of(b"ol\xc3\xa1, mundo\r\n\tfim\x0c"), text);
+        assert_eq!(kind_of("ação".as_bytes()), text);
+    }
+
+    #[test]
+    fn text_cut_at_sample_boundary() {
+        // "ç" tem dois bytes; a amostra terminou no meio dele
+        let bytes = "ação".as_bytes();
+        assert!(is_text(&bytes[..2]));
+        // Sequência inválida no meio não é texto
+        assert!(!is_text(b"a\xc3(b"));
+    }
+
+    #[test]
+    fn control_bytes_are_binary() {
+        assert!(!is_text(b"abc\0def"));
+        assert!(!is_text(b"abc\x1bdef"));
+        assert_eq!(detect(b"\x01\x02\x03"), None);
+    }
+
+    #[test]
+    fn empty_input_is_unknown() {
+        assert!(!is_text(b""));
+        assert_eq!(detect(b""), None);
+    }
+}
@@ -1,2 +1,5 @@
 pub mod constants;
-pub mod formatters;
\ No newline at end of file
+pub mod download;
+pub mod formatters;
+pub mod links;
+pub mod mime;
\ No newline at end of file
@@ -0,0 +1,135 @@
+// Service worker do Quickshare: guarda o shell (HTML, JS, WASM, CSS) para o
+// app abrir sem conexão. Chamadas à API (outra origem) nunca passam pelo cache.
+const CACHE = "quickshare-shell-v3";
+const SHELL = ["/", "/index.html", "/config.json", "/manifest.webmanifest", "/icon.svg"];
+
+// Mesmo banco usado pelo app (src/utils/constants.rs: IDB_NAME, IDB_VERSION, IDB_STORES)
+const DB_NAME = "quickshare";
+const DB_VERSION = 3;
+const DB_STORES = ["outbox", "share_inbox", "partial_downloads"];
+const SHARE_TARGET = "/compartilhar";
+// Só arquivos estáticos do build vão para o cache; o resto (downloads via
+// proxy, por exemplo) passa direto pela rede
+const STATIC_ASSET = /\.(?:js|wasm|css|svg|png|ico|webp|woff2?)$/;
+
+function openDb() {
+    return new Promise((resolve, reject) => {
+        const request = indexedDB.open(DB_NAME, DB_VERSION);
+        request.onupgradeneeded = () => {
+            const db = request.result;
+            for (const store of DB_STORES) {
+                if (!db.objectStoreNames.contains(store)) {
+                    db.createObjectStore(store);
+                }
+            }
+        };
+        request.onsuccess = () => {
+            const db = request.result;
+            // Uma aba com versão nova do banco não fica esperando por este
+            db.onversionchange = () => db.close();
+            resolve(db);
+        };
+        request.onerror = () => reject(request.error);
+        request.onblocked = () => reject(new Error("banco bloqueado por outra aba aberta do QuickShare"));
+    });
+}
+
+// Arquivos vindos do menu "Compartilhar" do sistema ficam no IndexedDB até o
+// usuário confirmar o envio na página /compartilhar
+async function receiveShare(request) {
+    const data = await request.formData();
+    const files = data.getAll("files").filter((file) => file instanceof File);
+
+    if (files.length > 0) {
+        const db = await openDb();
+        await new Promise((resolve, reject) => {
+            const tx = db.transaction("share_inbox", "readwrite");
+            const store = tx.objectStore("share_inbox");
+            files.forEach((file, index) => store.put(file, `${Date.now()}-${index}`));
+            tx.oncomplete = resolve;
+            tx.onerror = () => reject(tx.error);
+        });
+        db.close();
+    }
+
+    return Response.redirect(SHARE_TARGET, 303);
+}
+
+// O Trunk gera nomes com hash, então os assets são descobertos no próprio index.html
+async function precache() {
+    const cache = await caches.open(CACHE);
+    const response = await fetch("/index.html", { cache: "no-store" });
+    const html = await response.clone().text();
+    const assets = [...html.matchAll(/(?:href|src)="(\/[^"]+)"/g)].map((m) => m[1]);
+
+    await cache.put("/index.html", response);
+    await cache.addAll([...new Set([...SHELL, ...assets])].filter((url) => url !== "/index.html"));
+}
+
+self.addEventListener("install", (event) => {
+    event.waitUntil(precache().then(() => self.skipWaiting()));
+});
+
+self.addEventListener("activate", (event) => {
+    event.waitUntil(
+        caches
+            .keys()
+            .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+            .then(() => self.clients.claim())
+    );
+});
+
+// Rede primeiro, cache como reserva (navegação cai no index.html da SPA)
+async function networkFirst(request, fallback) {
+    const cache = await caches.open(CACHE);
+    try {
+        const response = await fetch(request);
+        if (response.ok) {
+            cache.put(fallback ?? request, response.clone());
+        }
+        return response;
+    } catch (error) {
+        const cached = await cache.match(fallback ?? request);
+        if (cached) {
+            return cached;
+        }
+        throw error;
+    }
+}
+
+// Assets com hash não mudam: cache primeiro
+async function cacheFirst(request) {
+    const cache = await caches.open(CACHE);
+    const cached = await cache.match(request);
+    if (cached) {
+        return cached;
+    }
+    const response = await fetch(request);
+    if (response.ok) {
+        cache.put(request, response.clone());
+    }
+    return response;
+}
+
+self.addEventListener("fetch", (event) => {
+    const request = event.request;
+    const url = new URL(request.url);
+
+    if (request.method === "POST" && url.origin === self.location.origin && url.pathname === SHARE_TARGET) {
+        event.respondWith(receiveShare(request));
+        return;
+    }
+
+    // Pedidos parciais (Range) não combinam com respostas inteiras do cache
+    if (request.method !== "GET" || url.origin !== self.location.origin || request.headers.has("Range")) {
+        return;
+    }
+
+    if (request.mode === "navigate") {
+        event.respondWith(networkFirst(request, "/index.html"));
+    } else if (url.pathname === "/config.json" || url.pathname === "/manifest.webmanifest") {
+        event.respondWith(networkFirst(request));
+    } else if (STATIC_ASSET.test(url.pathname)) {
+        event.respondWith(cacheFirst(request));
+    }
+});